@@ -0,0 +1,92 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ecc::{curves::Secp256k1, CurvePoint, Scalar, WeierstrassCurve};
+
+fn scalar_multiplication(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let point = Secp256k1::generator() * &Scalar::random(&mut rng);
+    let scalar = Scalar::<Secp256k1>::random(&mut rng);
+
+    let mut group = c.benchmark_group("secp256k1_scalar_multiplication");
+
+    // Multiplying with the unreduced integer bypasses the endomorphism.
+    group.bench_function("double_and_add", |b| {
+        b.iter(|| black_box(&point) * black_box(scalar.value()))
+    });
+
+    group.bench_function("glv", |b| b.iter(|| black_box(&point) * black_box(&scalar)));
+
+    for window in 2..=6 {
+        group.bench_with_input(BenchmarkId::new("wnaf", window), &window, |b, window| {
+            b.iter(|| black_box(&point).mul_wnaf(black_box(&scalar), *window))
+        });
+    }
+
+    group.finish();
+}
+
+fn generator_multiplication(c: &mut Criterion) {
+    let scalar = Scalar::<Secp256k1>::random(&mut rand::thread_rng());
+    let generator = Secp256k1::generator();
+
+    // Build the table outside of the measurement.
+    CurvePoint::generator_mul(&scalar);
+
+    let mut group = c.benchmark_group("secp256k1_generator_multiplication");
+
+    group.bench_function("double_and_add", |b| {
+        b.iter(|| black_box(&generator) * black_box(scalar.value()))
+    });
+
+    group.bench_function("generator_table", |b| {
+        b.iter(|| CurvePoint::generator_mul(black_box(&scalar)))
+    });
+
+    group.finish();
+}
+
+fn multi_scalar_multiplication(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let mut group = c.benchmark_group("secp256k1_multi_scalar_multiplication");
+    group.sample_size(10);
+
+    for term_count in [4, 64, 256] {
+        let terms = (0..term_count)
+            .map(|_| {
+                (
+                    Scalar::<Secp256k1>::random(&mut rng),
+                    CurvePoint::generator_mul(&Scalar::random(&mut rng)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        group.bench_with_input(
+            BenchmarkId::new("one_at_a_time", term_count),
+            &terms,
+            |b, terms| {
+                b.iter(|| {
+                    terms
+                        .iter()
+                        .fold(CurvePoint::point_at_infinity(), |sum, (scalar, point)| {
+                            sum + &(point * scalar)
+                        })
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("multi_scalar_mul", term_count),
+            &terms,
+            |b, terms| b.iter(|| CurvePoint::multi_scalar_mul(black_box(terms))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    scalar_multiplication,
+    generator_multiplication,
+    multi_scalar_multiplication
+);
+criterion_main!(benches);
@@ -0,0 +1,488 @@
+//! BLS signatures over [`Bls12_381`] as specified in
+//! [draft-irtf-cfrg-bls-signature-05](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05),
+//! with the ciphersuite `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` used by Ethereum's
+//! consensus layer.
+//!
+//! Public keys are points of `G1` and signatures are points of `G2`. A signature of a message is
+//! the hash of the message to `G2` multiplied with the secret key, and is verified by comparing
+//! two pairings. Signatures and public keys can be aggregated by adding them, so that a single
+//! signature attests that all signers signed their messages.
+//!
+//! Aggregation on its own is vulnerable to rogue-key attacks: an attacker can choose their
+//! public key as a function of other public keys, such that an aggregate signature that only
+//! they created verifies for the aggregate of all keys. To prevent this, every public key must
+//! come with a [proof of possession](prove_possession) of its secret key, which must be
+//! [verified](verify_possession) before the key is used in [`fast_aggregate_verify`].
+
+use hmac::{Mac, SimpleHmac};
+use num::{bigint::Sign, BigInt};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    curves::Bls12_381, hash_to_g2, pairing_check, CurvePoint, G2Point, Scalar, WeierstrassCurve,
+};
+
+/// The domain separation tag for hashing messages to `G2` when signing.
+pub const SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// The domain separation tag for hashing public keys to `G2` in proofs of possession.
+pub const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// A secret key, i.e. a non-zero scalar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretKey {
+    scalar: Scalar<Bls12_381>,
+}
+
+impl SecretKey {
+    /// Creates the secret key for `scalar` or returns `None` if it is zero.
+    pub fn new(scalar: Scalar<Bls12_381>) -> Option<Self> {
+        if scalar.is_zero() {
+            return None;
+        }
+
+        Some(Self { scalar })
+    }
+
+    /// Derives a secret key from the secret input keying material `ikm` and the optional
+    /// `key_info` with the `KeyGen` procedure from Section 2.3 of the specification.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ikm` is shorter than 32 bytes.
+    pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Self {
+        assert!(ikm.len() >= 32, "ikm must be at least 32 bytes long");
+
+        let mut salt = b"BLS-SIG-KEYGEN-SALT-".to_vec();
+        loop {
+            salt = Sha256::digest(&salt).to_vec();
+
+            // HKDF-Extract(salt, ikm || 0x00) followed by HKDF-Expand(prk, key_info || 48, 48),
+            // which needs two blocks of HMAC-SHA256 output.
+            let prk = hmac(&salt, &[ikm, &[0]]);
+            let info = [key_info, &48u16.to_be_bytes()].concat();
+            let t1 = hmac(&prk, &[&info, &[1]]);
+            let t2 = hmac(&prk, &[&t1, &info, &[2]]);
+            let okm = [&t1[..], &t2[..16]].concat();
+
+            let scalar = Scalar::new(BigInt::from_bytes_be(Sign::Plus, &okm));
+            if let Some(secret) = Self::new(scalar) {
+                return secret;
+            }
+        }
+    }
+
+    /// Generates a random secret key.
+    pub fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        // SAFETY: Random scalars are never zero.
+        Self::new(Scalar::random(rng)).unwrap()
+    }
+
+    /// Decodes a secret key from its 32-byte big-endian encoding.
+    ///
+    /// Returns `None` if the value is zero or not smaller than the group order.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        Self::new(Scalar::from_bytes_be(bytes)?)
+    }
+
+    /// Returns the 32-byte big-endian encoding of the secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        // SAFETY: Scalars of Bls12_381 are encoded with exactly 32 bytes.
+        self.scalar.to_bytes_be().try_into().unwrap()
+    }
+
+    /// Returns the secret scalar.
+    pub fn scalar(&self) -> &Scalar<Bls12_381> {
+        &self.scalar
+    }
+
+    /// Returns the public key `sk * G1`.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            point: Bls12_381::generator().mul_ct(&self.scalar),
+        }
+    }
+}
+
+/// A public key, i.e. a point of `G1` other than the point at infinity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    point: CurvePoint<Bls12_381>,
+}
+
+impl PublicKey {
+    /// Decodes a public key from its compressed encoding.
+    ///
+    /// Returns `None` if the encoding is invalid, the point is not in `G1` or is the point at
+    /// infinity, which implements `KeyValidate` of the specification.
+    pub fn from_bytes(bytes: &[u8; 48]) -> Option<Self> {
+        let point = CurvePoint::from_compressed(bytes).ok()?;
+        if point == CurvePoint::point_at_infinity() {
+            return None;
+        }
+
+        Some(Self { point })
+    }
+
+    /// Returns the compressed encoding of the public key.
+    pub fn to_bytes(&self) -> [u8; 48] {
+        self.point.to_compressed()
+    }
+
+    /// Returns the point of `G1` that the public key represents.
+    pub fn point(&self) -> &CurvePoint<Bls12_381> {
+        &self.point
+    }
+
+    /// Aggregates the public keys by adding them.
+    ///
+    /// Returns `None` if `keys` is empty or the keys add up to the point at infinity.
+    pub fn aggregate(keys: &[PublicKey]) -> Option<Self> {
+        let point = keys
+            .iter()
+            .fold(CurvePoint::point_at_infinity(), |sum, key| sum + &key.point);
+        if point == CurvePoint::point_at_infinity() {
+            return None;
+        }
+
+        Some(Self { point })
+    }
+}
+
+/// A signature, i.e. a point of `G2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    point: G2Point<Bls12_381>,
+}
+
+impl Signature {
+    /// Decodes a signature from its compressed encoding.
+    ///
+    /// Returns `None` if the encoding is invalid or the point is not in `G2`.
+    pub fn from_bytes(bytes: &[u8; 96]) -> Option<Self> {
+        G2Point::from_compressed(bytes)
+            .ok()
+            .map(|point| Self { point })
+    }
+
+    /// Returns the compressed encoding of the signature.
+    pub fn to_bytes(&self) -> [u8; 96] {
+        self.point.to_compressed()
+    }
+
+    /// Returns the point of `G2` that the signature represents.
+    pub fn point(&self) -> &G2Point<Bls12_381> {
+        &self.point
+    }
+
+    /// Aggregates the signatures by adding them.
+    ///
+    /// Returns `None` if `signatures` is empty.
+    pub fn aggregate(signatures: &[Signature]) -> Option<Self> {
+        if signatures.is_empty() {
+            return None;
+        }
+
+        let point = signatures
+            .iter()
+            .fold(G2Point::point_at_infinity(), |sum, signature| {
+                sum + &signature.point
+            });
+        Some(Self { point })
+    }
+}
+
+/// Signs `message` with the `secret` key.
+pub fn sign(secret: &SecretKey, message: &[u8]) -> Signature {
+    Signature {
+        point: hash_to_g2(message, SIGNATURE_DST).mul_ct(&secret.scalar),
+    }
+}
+
+/// Verifies that `signature` is a valid signature of `message` for the `public` key.
+pub fn verify(public: &PublicKey, message: &[u8], signature: &Signature) -> bool {
+    aggregate_verify(&[(public, message)], signature)
+}
+
+/// Verifies that `signature` is an aggregate of the signatures of all `(public key, message)`
+/// pairs.
+///
+/// This requires one pairing per pair, see [`fast_aggregate_verify`] for the faster check
+/// if all messages are the same.
+pub fn aggregate_verify(items: &[(&PublicKey, &[u8])], signature: &Signature) -> bool {
+    core_verify(
+        items
+            .iter()
+            .map(|(public, message)| (public.point.clone(), hash_to_g2(message, SIGNATURE_DST)))
+            .collect(),
+        signature,
+    )
+}
+
+/// Verifies that `signature` is an aggregate of the signatures of the same `message` for all
+/// `public` keys.
+///
+/// Since all messages are the same, the public keys can be aggregated first, which makes this
+/// as fast as verifying a single signature. This is only secure if the possession of the secret
+/// key has been [verified](verify_possession) for every public key.
+pub fn fast_aggregate_verify(public: &[PublicKey], message: &[u8], signature: &Signature) -> bool {
+    let Some(aggregate) = PublicKey::aggregate(public) else {
+        return false;
+    };
+
+    verify(&aggregate, message, signature)
+}
+
+/// Creates a proof of possession of the `secret` key, which is a signature of the public key
+/// with a separate domain separation tag.
+pub fn prove_possession(secret: &SecretKey) -> Signature {
+    let message = secret.public_key().to_bytes();
+    Signature {
+        point: hash_to_g2(&message, POP_DST).mul_ct(&secret.scalar),
+    }
+}
+
+/// Verifies that `proof` proves the possession of the secret key of the `public` key.
+pub fn verify_possession(public: &PublicKey, proof: &Signature) -> bool {
+    let message = hash_to_g2(&public.to_bytes(), POP_DST);
+    core_verify(vec![(public.point.clone(), message)], proof)
+}
+
+/// Checks that `e(P_1, H_1) * ... * e(P_n, H_n) = e(G1, signature)` for all pairs `(P_i, H_i)`
+/// of public keys and hashed messages.
+fn core_verify(
+    mut pairs: Vec<(CurvePoint<Bls12_381>, G2Point<Bls12_381>)>,
+    signature: &Signature,
+) -> bool {
+    if pairs.is_empty() {
+        return false;
+    }
+
+    pairs.push((-Bls12_381::generator(), signature.point.clone()));
+    pairing_check(&pairs)
+}
+
+/// Computes `HMAC-SHA256_key(data[0] || data[1] || ...)`.
+fn hmac(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    // SAFETY: HMAC accepts keys of any length.
+    let mut mac = <SimpleHmac<Sha256> as Mac>::new_from_slice(key).unwrap();
+    for data in data {
+        mac.update(data);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::hex;
+
+    use super::*;
+
+    /// Test vector for the master key derivation of
+    /// [EIP-2333](https://eips.ethereum.org/EIPS/eip-2333), which is `KeyGen` with empty
+    /// `key_info`.
+    #[test]
+    fn key_gen_test_vector() {
+        let seed = hex(concat!(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d1826",
+            "4c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        ));
+        let secret = SecretKey::key_gen(&seed, &[]);
+
+        assert_eq!(
+            secret.scalar().value(),
+            &BigInt::parse_bytes(
+                b"6083874454709270928345386274498605044986640685124978867557563392430687146096",
+                10
+            )
+            .unwrap()
+        );
+    }
+
+    /// The secret keys and public keys of the BLS test vectors of the
+    /// [Ethereum consensus specification](https://github.com/ethereum/consensus-specs/tree/dev/tests/generators/bls),
+    /// which use the proof of possession ciphersuite with public keys in G1.
+    const KEYS: [(&str, &str); 3] = [
+        (
+            "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+            "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+        ),
+        (
+            "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+            "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+        ),
+        (
+            "328388aff0d4a5b7dc9205abd374e7e98f3cd9f3418edb4eafda5fb16473d216",
+            "b53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f",
+        ),
+    ];
+
+    /// The messages of the Ethereum consensus test vectors.
+    const MESSAGES: [[u8; 32]; 3] = [[0x00; 32], [0x56; 32], [0xab; 32]];
+
+    fn keys() -> [(SecretKey, PublicKey); 3] {
+        KEYS.map(|(secret, public)| {
+            (
+                SecretKey::from_bytes(&hex(secret).try_into().unwrap()).unwrap(),
+                PublicKey::from_bytes(&hex(public).try_into().unwrap()).unwrap(),
+            )
+        })
+    }
+
+    fn signature_from_hex(value: &str) -> Signature {
+        Signature::from_bytes(&hex(value).try_into().unwrap()).unwrap()
+    }
+
+    /// The `sign` and `verify` test vectors of the Ethereum consensus specification, where each
+    /// key signs each message.
+    #[test]
+    fn sign_test_vectors() {
+        let signatures = [
+            [
+                concat!(
+                    "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb515809",
+                    "0352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55",
+                ),
+                concat!(
+                    "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c2",
+                    "0767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb",
+                ),
+                concat!(
+                    "91347bccf740d859038fcdcaf233eeceb2a436bcaaee9b2aa3bfb70efe29dfb2677562ccbea1c8e061fb9971b0753c24",
+                    "0622fab78489ce96768259fc01360346da5b9f579e5da0d941e4c6ba18a0e64906082375394f337fa1af2b7127b0d121",
+                ),
+            ],
+            [
+                concat!(
+                    "b23c46be3a001c63ca711f87a005c200cc550b9429d5f4eb38d74322144f1b63926da3388979e5321012fb1a0526bcd1",
+                    "00b5ef5fe72628ce4cd5e904aeaa3279527843fae5ca9ca675f4f51ed8f83bbf7155da9ecc9663100a885d5dc6df96d9",
+                ),
+                concat!(
+                    "af1390c3c47acdb37131a51216da683c509fce0e954328a59f93aebda7e4ff974ba208d9a4a2a2389f892a9d418d6184",
+                    "18dd7f7a6bc7aa0da999a9d3a5b815bc085e14fd001f6a1948768a3f4afefc8b8240dda329f984cb345c6363272ba4fe",
+                ),
+                concat!(
+                    "9674e2228034527f4c083206032b020310face156d4a4685e2fcaec2f6f3665aa635d90347b6ce124eb879266b1e801d",
+                    "185de36a0a289b85e9039662634f2eea1e02e670bc7ab849d006a70b2f93b84597558a05b879c8d445f387a5d5b653df",
+                ),
+            ],
+            [
+                concat!(
+                    "948a7cb99f76d616c2c564ce9bf4a519f1bea6b0a624a02276443c245854219fabb8d4ce061d255af5330b078d538068",
+                    "1751aa7053da2c98bae898edc218c75f07e24d8802a17cd1f6833b71e58f5eb5b94208b4d0bb3848cecb075ea21be115",
+                ),
+                concat!(
+                    "a4efa926610b8bd1c8330c918b7a5e9bf374e53435ef8b7ec186abf62e1b1f65aeaaeb365677ac1d1172a1f5b44b4e6d",
+                    "022c252c58486c0a759fbdc7de15a756acc4d343064035667a594b4c2a6f0b0b421975977f297dba63ee2f63ffe47bb6",
+                ),
+                concat!(
+                    "ae82747ddeefe4fd64cf9cedb9b04ae3e8a43420cd255e3c7cd06a8d88b7c7f8638543719981c5d16fa3527c468c25f0",
+                    "026704a6951bde891360c7e8d12ddee0559004ccdbe6046b55bae1b257ee97f7cdb955773d7cf29adf3ccbb9975e4eb9",
+                ),
+            ],
+        ];
+
+        for ((secret, public), signatures) in keys().iter().zip(signatures) {
+            assert_eq!(&secret.public_key(), public);
+
+            for (message, expected) in MESSAGES.iter().zip(signatures) {
+                let expected = signature_from_hex(expected);
+                assert_eq!(sign(secret, message), expected);
+                assert!(verify(public, message, &expected));
+                assert!(!verify(public, b"other message", &expected));
+            }
+
+            let proof = prove_possession(secret);
+            assert!(verify_possession(public, &proof));
+            // A signature of the encoded public key is not a proof of possession.
+            assert!(!verify_possession(
+                public,
+                &sign(secret, &public.to_bytes())
+            ));
+        }
+    }
+
+    /// The `fast_aggregate_verify` and `aggregate_verify` test vectors of the Ethereum consensus
+    /// specification.
+    #[test]
+    fn aggregate_verify_test_vectors() {
+        let public = keys().map(|(_, public)| public);
+
+        // All keys sign the same message.
+        let signature = signature_from_hex(concat!(
+            "9712c3edd73a209c742b8250759db12549b3eaf43b5ca61376d9f30e2747dbcf842d8b2ac0901d2a093713e20284a767",
+            "0fcf6954e9ab93de991bb9b313e664785a075fc285806fa5224c82bde146561b446ccfc706a64b8579513cfc4ff1d930",
+        ));
+        let message = &MESSAGES[2];
+        assert!(fast_aggregate_verify(&public, message, &signature));
+        assert!(!fast_aggregate_verify(&public[..2], message, &signature));
+        assert!(!fast_aggregate_verify(&public, &MESSAGES[1], &signature));
+
+        // Each key signs its own message.
+        let signature = signature_from_hex(concat!(
+            "9104e74b9dfd3ad502f25d6a5ef57db0ed7d9a0e00f3500586d8ce44231212542fcfaf87840539b398bf07626705cf11",
+            "05d246ca1062c6c2e1a53029a0f790ed5e3cb1f52f8234dc5144c45fc847c0cd37a92d68e7c5ba7c648a8a339f171244",
+        ));
+        let items = [0, 1, 2].map(|i| (&public[i], MESSAGES[i].as_slice()));
+        assert!(aggregate_verify(&items, &signature));
+        assert!(!aggregate_verify(&items[..2], &signature));
+    }
+
+    #[test]
+    fn aggregate_signatures() {
+        let mut rng = rand::thread_rng();
+        let secrets = [(); 3].map(|_| SecretKey::random(&mut rng));
+        let public = secrets.each_ref().map(SecretKey::public_key);
+
+        let message = b"attestation";
+        let signature =
+            Signature::aggregate(&secrets.each_ref().map(|secret| sign(secret, message))).unwrap();
+        assert!(fast_aggregate_verify(&public, message, &signature));
+        assert!(!fast_aggregate_verify(&public[..2], message, &signature));
+        assert!(!fast_aggregate_verify(&[], message, &signature));
+
+        let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+        let signature =
+            Signature::aggregate(&[0, 1, 2].map(|i| sign(&secrets[i], messages[i]))).unwrap();
+        let items = [0, 1, 2].map(|i| (&public[i], messages[i]));
+        assert!(aggregate_verify(&items, &signature));
+        let swapped = [0, 2, 1].map(|i| (&public[i], messages[(i + 1) % 3]));
+        assert!(!aggregate_verify(&swapped, &signature));
+        assert!(!aggregate_verify(&[], &signature));
+    }
+
+    /// Shows how proofs of possession prevent rogue-key attacks.
+    #[test]
+    fn rogue_key_attack() {
+        let mut rng = rand::thread_rng();
+        let victim = SecretKey::random(&mut rng).public_key();
+        let attacker = SecretKey::random(&mut rng);
+
+        // The rogue key is chosen such that the aggregate key is the attacker's key.
+        let rogue_key = PublicKey {
+            point: attacker.public_key().point - &victim.point,
+        };
+        let message = b"the victim agrees";
+        let signature = sign(&attacker, message);
+        assert!(fast_aggregate_verify(
+            &[victim.clone(), rogue_key.clone()],
+            message,
+            &signature
+        ));
+
+        // But the attacker cannot prove the possession of the rogue key's secret key.
+        assert!(!verify_possession(&rogue_key, &prove_possession(&attacker)));
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        assert_eq!(SecretKey::from_bytes(&[0; 32]), None);
+        let (_, order) = Bls12_381::order().to_bytes_be();
+        assert_eq!(SecretKey::from_bytes(&order.try_into().unwrap()), None);
+
+        let infinity = CurvePoint::<Bls12_381>::point_at_infinity().to_compressed();
+        assert_eq!(PublicKey::from_bytes(&infinity), None);
+        assert_eq!(PublicKey::aggregate(&[]), None);
+        assert_eq!(Signature::aggregate(&[]), None);
+    }
+}
@@ -1,26 +1,60 @@
 use std::{
     marker::PhantomData,
-    ops::{Add, Mul},
+    ops::{Add, Mul, Neg, Sub},
 };
 
-use num::{traits::Euclid, BigInt};
+use num::BigInt;
 
-use crate::{mod_mul_inverse, WeierstrassCurve};
+use crate::{
+    msm,
+    point_arithmetic::{self, StaticCurve},
+    projective::ProjectivePoint,
+    Endomorphism, FieldElement, PointError, Scalar, WeierstrassCurve,
+};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub struct CurvePoint<C: WeierstrassCurve> {
-    point: Point,
+    point: Point<C>,
     phantom: PhantomData<C>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Point {
+#[derive(Debug)]
+pub enum Point<C: WeierstrassCurve> {
     PointAtInfinity,
-    Point { x: BigInt, y: BigInt },
+    Point {
+        x: FieldElement<C>,
+        y: FieldElement<C>,
+    },
+}
+
+impl<C: WeierstrassCurve> Clone for Point<C> {
+    fn clone(&self) -> Self {
+        match self {
+            Point::PointAtInfinity => Point::PointAtInfinity,
+            Point::Point { x, y } => Point::Point {
+                x: x.clone(),
+                y: y.clone(),
+            },
+        }
+    }
+}
+
+impl<C: WeierstrassCurve> PartialEq for Point<C> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Point::PointAtInfinity, Point::PointAtInfinity) => true,
+            (Point::Point { x: x_p, y: y_p }, Point::Point { x: x_q, y: y_q }) => {
+                x_p == x_q && y_p == y_q
+            }
+            _ => false,
+        }
+    }
 }
 
-impl<C: WeierstrassCurve> From<Point> for CurvePoint<C> {
-    fn from(point: Point) -> Self {
+impl<C: WeierstrassCurve> Eq for Point<C> {}
+
+impl<C: WeierstrassCurve> From<Point<C>> for CurvePoint<C> {
+    fn from(point: Point<C>) -> Self {
         Self {
             point,
             phantom: PhantomData,
@@ -34,129 +68,198 @@ impl<C: WeierstrassCurve> Clone for CurvePoint<C> {
     }
 }
 
+impl<C: WeierstrassCurve> PartialEq for CurvePoint<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<C: WeierstrassCurve> Eq for CurvePoint<C> {}
+
 impl<C: WeierstrassCurve> CurvePoint<C> {
     /// Creates a new point on the curve with the given coordinates.
     ///
-    /// At present, it does not check whether the point is actually on the curve.
-    pub fn new(x: impl Into<BigInt>, y: impl Into<BigInt>) -> Self {
+    /// Returns an error if a coordinate is not in the range `[0, field_modulus)` or if the point
+    /// does not satisfy the curve equation. Use this whenever the coordinates come from an
+    /// untrusted source, to protect against invalid-curve attacks.
+    pub fn try_new(x: impl Into<BigInt>, y: impl Into<BigInt>) -> Result<Self, PointError> {
+        point_arithmetic::try_new(StaticCurve::new(), x.into(), y.into())
+    }
+
+    /// Creates a new point on the curve with the given coordinates without validating them.
+    ///
+    /// The coordinates are reduced modulo the field modulus.
+    /// Only use this for trusted inputs, such as constants. Otherwise use [`CurvePoint::try_new`].
+    pub fn new_unchecked(x: impl Into<BigInt>, y: impl Into<BigInt>) -> Self {
         Point::Point {
-            x: x.into(),
-            y: y.into(),
+            x: FieldElement::new(x),
+            y: FieldElement::new(y),
         }
         .into()
     }
 
     /// Returns the underlying point's x, y coordinates in that order
     /// or `None` if it's the point at infinity.
-    pub fn as_coordinates(&self) -> Option<(&BigInt, &BigInt)> {
+    pub fn as_coordinates(&self) -> Option<(&FieldElement<C>, &FieldElement<C>)> {
         match &self.point {
             Point::PointAtInfinity => None,
             Point::Point { x, y } => Some((x, y)),
         }
     }
 
+    /// Encodes the point as defined in Section 2.3.3 of [SEC 1](https://www.secg.org/sec1-v2.pdf).
+    ///
+    /// The point at infinity is encoded as the single byte `0x00`. Otherwise, the encoding
+    /// consists of a prefix followed by the coordinates as fixed-width big-endian integers:
+    /// the uncompressed encoding is `0x04 || x || y`, the compressed encoding is `0x02 || x`
+    /// if `y` is even and `0x03 || x` if `y` is odd.
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        point_arithmetic::to_sec1::<StaticCurve<C>>(self, compressed)
+    }
+
+    /// Decodes a point from its [SEC 1](https://www.secg.org/sec1-v2.pdf) encoding,
+    /// see [`CurvePoint::to_sec1`].
+    ///
+    /// Returns an error if the prefix is unknown, the length does not match the prefix,
+    /// a coordinate is not smaller than the field modulus or the point is not on the curve.
+    /// For compressed points, the latter means that there is no `y` for the given `x`.
+    pub fn from_sec1(bytes: &[u8]) -> Result<Self, PointError> {
+        point_arithmetic::from_sec1(StaticCurve::new(), bytes)
+    }
+
     /// Returns the underlying [`Point`].
-    pub fn point(&self) -> &Point {
+    pub fn point(&self) -> &Point<C> {
         &self.point
     }
 
+    /// Returns `true` if the point satisfies the curve equation `y^2 = x^3 + ax + b`.
+    ///
+    /// The point at infinity is always on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        point_arithmetic::is_on_curve(StaticCurve::new(), self)
+    }
+
+    /// Returns `true` if the point is on the curve and in the subgroup generated by the curve's
+    /// generator, i.e. if multiplying it with the group order results in the point at infinity.
+    ///
+    /// This always holds for points on curves with a cofactor of one.
+    pub fn is_in_subgroup(&self) -> bool {
+        if !self.is_on_curve() {
+            return false;
+        }
+
+        C::cofactor() == BigInt::from(1)
+            || self.multiply(&C::order()).point == Point::PointAtInfinity
+    }
+
     /// Creates the `CurvePoint` representing the point at infinity, i.e. the identity element.
     pub fn point_at_infinity() -> Self {
         Self::from(Point::PointAtInfinity)
     }
 
     /// Multiplies `scalar` with `p` in logarithmic time.
+    ///
+    /// Negative scalars are handled by multiplying the negated point with the absolute value.
+    /// The intermediate results are kept in Jacobian coordinates, so that the whole
+    /// multiplication requires only a single field inversion.
     fn multiply(&self, scalar: &BigInt) -> CurvePoint<C> {
-        if scalar == &BigInt::ZERO {
-            return Point::PointAtInfinity.into();
-        }
+        point_arithmetic::multiply(StaticCurve::new(), self, scalar)
+    }
 
-        // The number of doublings we need to efficiently compute the multiplication.
-        let doublings = (scalar.bits() - 1) as usize;
+    /// Multiplies `scalar` with `p` using the GLV method.
+    ///
+    /// The scalar is decomposed into `k1 + k2 * λ` with half-length `k1` and `k2`, so that
+    /// `k1 * P + k2 * φ(P)` can be computed with Straus' method and half the number of doublings.
+    fn multiply_glv(&self, scalar: &Scalar<C>, endomorphism: &Endomorphism<C>) -> CurvePoint<C> {
+        let (k1, k2) = endomorphism.decompose(scalar);
+
+        // Negative halves are handled by negating the corresponding point.
+        let term = |k: BigInt, point: CurvePoint<C>| {
+            if k < BigInt::ZERO {
+                (-k, point.negate())
+            } else {
+                (k, point)
+            }
+        };
 
-        // Create the doubling cache.
-        // The ith entry in the cache is the result of 2^i * p.
-        // +1 capacity to account for the 0th entry we add manually.
-        let mut double_cache: Vec<CurvePoint<C>> = Vec::with_capacity(doublings + 1);
-        double_cache.push(self.clone());
+        msm::straus_unreduced(&[term(k1, self.clone()), term(k2, endomorphism.apply(self))])
+    }
 
-        for i in 1..=doublings {
-            double_cache.push(&double_cache[i - 1] + &double_cache[i - 1]);
-        }
+    /// Multiplies the curve's generator with `scalar`.
+    ///
+    /// This uses the curve's precomputed [`GeneratorTable`](crate::GeneratorTable) and is
+    /// considerably faster than multiplying [`WeierstrassCurve::generator`] with the scalar.
+    /// The table is built on first use.
+    pub fn generator_mul(scalar: &Scalar<C>) -> CurvePoint<C> {
+        C::generator_table().multiply(scalar)
+    }
 
-        let mut result = Point::PointAtInfinity.into();
-        let mut scalar = scalar.clone();
-        let two = BigInt::from(2);
+    /// Computes the sum `k_1 * P_1 + ... + k_n * P_n` of all `(k_i, P_i)` in `terms`.
+    ///
+    /// This is much faster than multiplying and adding the terms one at a time. For a few terms,
+    /// Straus' method is used, which shares the doublings between all terms. For many terms,
+    /// Pippenger's bucket method is used, which needs roughly a constant number of additions
+    /// per term. The running time depends on the scalars, so do not use this for secret scalars.
+    pub fn multi_scalar_mul(terms: &[(Scalar<C>, CurvePoint<C>)]) -> CurvePoint<C> {
+        msm::multi_scalar_mul(terms)
+    }
 
-        while scalar != BigInt::ZERO {
-            // SAFETY: Subtracting 1 is fine since we never enter the loop
-            // if scalar is zero, and hence bits() always returns > 0.
-            let next_smaller_power_of_two = scalar.bits() - 1;
-            scalar -= two.pow(next_smaller_power_of_two as u32);
-            result = &result + &double_cache[next_smaller_power_of_two as usize];
-        }
+    /// Multiplies `self` with the public `scalar` using its width-`window` non-adjacent form.
+    ///
+    /// This precomputes the odd multiples `P, 3P, ..., (2^(window - 1) - 1)P` and requires roughly
+    /// one addition per `window + 1` bits of the scalar, compared to one addition per set bit for
+    /// the regular multiplication. Larger windows need fewer additions but a bigger table,
+    /// for 256-bit scalars a window of 4 or 5 is usually optimal.
+    ///
+    /// The running time depends on the scalar, so do not use this for secret scalars.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `window` is not in the range `[2, 16]`.
+    pub fn mul_wnaf(&self, scalar: &Scalar<C>, window: usize) -> CurvePoint<C> {
+        point_arithmetic::mul_wnaf(StaticCurve::new(), self, scalar.value(), window)
+    }
 
-        result
+    /// Multiplies `self` with the secret `scalar` using a Montgomery ladder.
+    ///
+    /// Unlike the regular multiplication, the ladder always iterates over as many bits as the
+    /// group order has, performs exactly one addition and one doubling per bit using the
+    /// [complete addition formulas](CurvePoint::add_complete) and swaps its intermediate points
+    /// arithmetically instead of branching on the scalar's bits. This makes the sequence of
+    /// field operations independent of the scalar, so use this method for secret scalars,
+    /// e.g. private keys in ECDH.
+    ///
+    /// Note that the underlying [`BigInt`] arithmetic itself is not constant-time,
+    /// so this protects against timing leaks from the algorithm's control flow only.
+    /// The result is only guaranteed to be correct on curves of prime order.
+    pub fn mul_ct(&self, scalar: &Scalar<C>) -> CurvePoint<C> {
+        point_arithmetic::mul_ct(StaticCurve::new(), self, scalar.value())
     }
 
     /// Adds `q` to `self` on the elliptic curve.
-    ///
-    /// Formulas taken from https://en.wikipedia.org/wiki/Elliptic_curve_point_multiplication.
     fn add(&self, q: &CurvePoint<C>) -> CurvePoint<C> {
-        let p = &self.point;
-        let q = &q.point;
-
-        match (p, q) {
-            (Point::PointAtInfinity, Point::PointAtInfinity) => Point::PointAtInfinity.into(),
-            (Point::PointAtInfinity, Point::Point { .. }) => q.clone().into(),
-            (Point::Point { .. }, Point::PointAtInfinity) => p.clone().into(),
-            (Point::Point { x: x_p, y: y_p }, Point::Point { x: x_q, y: y_q }) => {
-                if p == q {
-                    let lambda =
-                        (3 * x_p.pow(2) + C::a()) * (mod_mul_inverse(2 * y_p, C::field_modulus()));
-                    let lambda = Euclid::rem_euclid(&lambda, &C::field_modulus());
-
-                    let x_r = lambda.pow(2) - 2 * x_p;
-                    let x_r = Euclid::rem_euclid(&x_r, &C::field_modulus());
-
-                    let y_r = lambda * (-&x_r + x_p) - y_p;
-                    let y_r = Euclid::rem_euclid(&y_r, &C::field_modulus());
-
-                    Point::Point { x: x_r, y: y_r }.into()
-                } else if x_p == x_q {
-                    // If the x-coordinates match, there will be no intersection with a third point,
-                    // so we return the point at infinity.
-                    Point::PointAtInfinity.into()
-                } else {
-                    let lambda = (y_q - y_p) * mod_mul_inverse(x_q - x_p, C::field_modulus());
-                    let lambda = Euclid::rem_euclid(&lambda, &C::field_modulus());
-
-                    let x_r = lambda.pow(2) - x_p - x_q;
-                    let x_r = Euclid::rem_euclid(&x_r, &C::field_modulus());
-
-                    let y_r = lambda * (x_p - &x_r) - y_p;
-                    let y_r = Euclid::rem_euclid(&y_r, &C::field_modulus());
+        point_arithmetic::add(StaticCurve::new(), self, q)
+    }
 
-                    Point::Point { x: x_r, y: y_r }.into()
-                }
-            }
-        }
+    /// Adds `q` to `self` using the complete addition formulas by Renes, Costello and Batina.
+    ///
+    /// Unlike the regular addition, this executes the same sequence of field operations regardless
+    /// of whether the points are equal, each other's negation or the point at infinity.
+    /// The result is only guaranteed to be correct on curves of prime order.
+    pub fn add_complete(&self, q: &CurvePoint<C>) -> CurvePoint<C> {
+        ProjectivePoint::from(self)
+            .add(&ProjectivePoint::from(q))
+            .to_affine()
     }
 
     /// Returns the inverse `inv` of `self` such that `self` + `inv` equals the [`Point::PointAtInfinity`].
     pub fn negate(&self) -> CurvePoint<C> {
-        let Point::Point { x, y } = &self.point else {
-            // The inverse of the point at infinity is itself.
-            return Point::PointAtInfinity.into();
-        };
+        point_arithmetic::negate(StaticCurve::new(), self)
+    }
 
-        // The inverse of a point has the same x-coordinate,
-        // and the new y value satisfies old_y * new_y mod field_modulus = 1, i.e. the modular multiplicate inverse.
-        Point::Point {
-            x: x.clone(),
-            y: mod_mul_inverse(y.clone(), C::field_modulus()),
-        }
-        .into()
+    /// Subtracts `q` from `self` on the elliptic curve, i.e. computes `self + (-q)`.
+    fn subtract(&self, q: &CurvePoint<C>) -> CurvePoint<C> {
+        CurvePoint::add(self, &q.negate())
     }
 }
 
@@ -177,6 +280,64 @@ impl<C: WeierstrassCurve> Add<&CurvePoint<C>> for CurvePoint<C> {
     }
 }
 
+impl<C: WeierstrassCurve> Sub<&CurvePoint<C>> for &CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn sub(self, q: &CurvePoint<C>) -> Self::Output {
+        CurvePoint::subtract(self, q)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Sub<&CurvePoint<C>> for CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn sub(self, q: &CurvePoint<C>) -> Self::Output {
+        CurvePoint::subtract(&self, q)
+    }
+}
+
+impl<C: WeierstrassCurve> Neg for &CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn neg(self) -> Self::Output {
+        CurvePoint::negate(self)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Neg for CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn neg(self) -> Self::Output {
+        CurvePoint::negate(&self)
+    }
+}
+
+impl<C: WeierstrassCurve> Mul<&Scalar<C>> for &CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn mul(self, scalar: &Scalar<C>) -> Self::Output {
+        match C::endomorphism() {
+            Some(endomorphism) => CurvePoint::multiply_glv(self, scalar, endomorphism),
+            None => CurvePoint::multiply(self, scalar.value()),
+        }
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Mul<&Scalar<C>> for CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn mul(self, scalar: &Scalar<C>) -> Self::Output {
+        &self * scalar
+    }
+}
+
+/// Multiplies the point with an arbitrary integer, which is not reduced modulo the group order.
+///
+/// This is useful for points outside of the subgroup generated by the generator,
+/// e.g. for clearing the cofactor. Prefer multiplying with a [`Scalar`] otherwise.
 impl<C: WeierstrassCurve> Mul<&BigInt> for &CurvePoint<C> {
     type Output = CurvePoint<C>;
 
@@ -193,3 +354,367 @@ impl<C: WeierstrassCurve> Mul<&BigInt> for CurvePoint<C> {
         CurvePoint::multiply(&self, scalar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+    use num::{bigint::Sign, traits::Euclid};
+
+    use crate::curves::{
+        Bn128, Mod17TestCurve, PrimeOrderTestCurve, Secp256k1, TestCurve, P256, P384, P521,
+    };
+
+    use super::*;
+
+    /// Returns `count` random points of the group generated by the curve's generator.
+    fn random_points<C: WeierstrassCurve>(count: usize) -> Vec<CurvePoint<C>> {
+        (0..count)
+            .map(|_| {
+                let scalar = BigInt::from_bytes_be(Sign::Plus, &rand::random::<[u8; 32]>());
+                C::generator() * &Euclid::rem_euclid(&scalar, &C::order())
+            })
+            .collect()
+    }
+
+    fn check_negation_properties<C: WeierstrassCurve + std::fmt::Debug + PartialEq>(
+        points: &[CurvePoint<C>],
+    ) {
+        let infinity = CurvePoint::<C>::point_at_infinity();
+
+        for p in points {
+            let negated = -p;
+            assert!(negated.is_on_curve());
+            assert_eq!(p + &negated, infinity);
+            assert_eq!(-&negated, *p);
+
+            for q in points {
+                assert_eq!(p - q, p + &(-q));
+                assert_eq!(&(p - q) + q, *p);
+            }
+        }
+    }
+
+    #[test]
+    fn negation_and_subtraction_test_curve() {
+        // The test curve is small enough to check every point of the group.
+        let points = (0..12)
+            .map(|scalar| TestCurve::generator() * &BigInt::from(scalar))
+            .collect::<Vec<_>>();
+
+        check_negation_properties(&points);
+    }
+
+    #[test]
+    fn negation_and_subtraction_bn128() {
+        let mut points = random_points::<Bn128>(4);
+        points.push(CurvePoint::point_at_infinity());
+
+        check_negation_properties(&points);
+    }
+
+    #[test]
+    fn negation_and_subtraction_secp256k1() {
+        let mut points = random_points::<Secp256k1>(4);
+        points.push(CurvePoint::point_at_infinity());
+
+        check_negation_properties(&points);
+    }
+
+    #[test]
+    fn negation_and_subtraction_nist() {
+        fn check<C: WeierstrassCurve + std::fmt::Debug + PartialEq>() {
+            let mut points = random_points::<C>(3);
+            points.push(CurvePoint::point_at_infinity());
+
+            check_negation_properties(&points);
+        }
+
+        check::<P256>();
+        check::<P384>();
+        check::<P521>();
+    }
+
+    fn check_constant_time_multiplication<C: WeierstrassCurve + std::fmt::Debug>(
+        scalars: impl IntoIterator<Item = Scalar<C>>,
+    ) {
+        let point = C::generator() * &Scalar::new(7);
+        for scalar in scalars {
+            assert_eq!(point.mul_ct(&scalar), &point * &scalar);
+        }
+    }
+
+    #[test]
+    fn constant_time_multiplication() {
+        let mut rng = rand::thread_rng();
+
+        check_constant_time_multiplication::<PrimeOrderTestCurve>((0..83).map(Scalar::new));
+        check_constant_time_multiplication::<Bn128>(
+            [Scalar::zero(), Scalar::one(), -Scalar::one()]
+                .into_iter()
+                .chain((0..3).map(|_| Scalar::random(&mut rng))),
+        );
+        check_constant_time_multiplication::<Secp256k1>(
+            [Scalar::zero(), Scalar::one(), -Scalar::one()]
+                .into_iter()
+                .chain((0..3).map(|_| Scalar::random(&mut rng))),
+        );
+
+        check_constant_time_multiplication::<P256>(
+            [Scalar::zero(), Scalar::one(), -Scalar::one()]
+                .into_iter()
+                .chain((0..3).map(|_| Scalar::random(&mut rng))),
+        );
+
+        assert_eq!(
+            CurvePoint::<Secp256k1>::point_at_infinity().mul_ct(&Scalar::new(5)),
+            CurvePoint::point_at_infinity()
+        );
+    }
+
+    #[test]
+    fn wnaf_multiplication() {
+        let mut rng = rand::thread_rng();
+        let scalars = [Scalar::zero(), Scalar::one(), -Scalar::one()]
+            .into_iter()
+            .chain((0..3).map(|_| Scalar::<Secp256k1>::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let point = Secp256k1::generator() * &Scalar::new(11);
+
+        for window in 2..=6 {
+            for scalar in &scalars {
+                assert_eq!(point.mul_wnaf(scalar, window), &point * scalar);
+            }
+        }
+
+        for scalar in 0..83 {
+            let scalar = Scalar::new(scalar);
+            assert_eq!(
+                PrimeOrderTestCurve::generator().mul_wnaf(&scalar, 3),
+                PrimeOrderTestCurve::generator() * &scalar
+            );
+        }
+    }
+
+    fn check_generator_multiplication<C: WeierstrassCurve + std::fmt::Debug>(
+        scalars: impl IntoIterator<Item = Scalar<C>>,
+    ) {
+        for scalar in scalars {
+            assert_eq!(
+                CurvePoint::<C>::generator_mul(&scalar),
+                C::generator() * &scalar
+            );
+        }
+    }
+
+    #[test]
+    fn generator_multiplication() {
+        let mut rng = rand::thread_rng();
+
+        check_generator_multiplication::<TestCurve>((0..12).map(Scalar::new));
+        check_generator_multiplication::<PrimeOrderTestCurve>((0..83).map(Scalar::new));
+        check_generator_multiplication::<Mod17TestCurve>((0..19).map(Scalar::new));
+        check_generator_multiplication::<Bn128>(
+            [Scalar::zero(), Scalar::one(), -Scalar::one()]
+                .into_iter()
+                .chain((0..5).map(|_| Scalar::random(&mut rng))),
+        );
+        check_generator_multiplication::<Secp256k1>(
+            [Scalar::zero(), Scalar::one(), -Scalar::one()]
+                .into_iter()
+                .chain((0..5).map(|_| Scalar::random(&mut rng))),
+        );
+        check_generator_multiplication::<P384>(
+            [Scalar::zero(), Scalar::one(), -Scalar::one()]
+                .into_iter()
+                .chain((0..5).map(|_| Scalar::random(&mut rng))),
+        );
+    }
+
+    fn check_glv_multiplication<C: WeierstrassCurve + std::fmt::Debug>() {
+        let mut rng = rand::thread_rng();
+        let points = [
+            C::generator(),
+            C::generator() * &BigInt::from(12345),
+            CurvePoint::point_at_infinity(),
+        ];
+        let scalars = [Scalar::zero(), Scalar::one(), -Scalar::one()]
+            .into_iter()
+            .chain((0..10).map(|_| Scalar::random(&mut rng)));
+
+        for scalar in scalars {
+            for point in &points {
+                // Multiplying with a BigInt does not use the endomorphism.
+                assert_eq!(point * &scalar, point * scalar.value());
+            }
+        }
+    }
+
+    #[test]
+    fn glv_multiplication() {
+        check_glv_multiplication::<Bn128>();
+        check_glv_multiplication::<Secp256k1>();
+    }
+
+    /// Test that the SEC1 encodings round-trip with k256's encodings.
+    #[test]
+    fn sec1_encoding_secp256k1() {
+        let mut rng = rand::thread_rng();
+        let points = (0..10)
+            .map(|_| CurvePoint::generator_mul(&Scalar::<Secp256k1>::random(&mut rng)))
+            .chain([CurvePoint::point_at_infinity()]);
+
+        for point in points {
+            for compressed in [false, true] {
+                let bytes = point.to_sec1(compressed);
+                assert_eq!(CurvePoint::from_sec1(&bytes), Ok(point.clone()));
+
+                let encoded_point = k256::EncodedPoint::from_bytes(&bytes).unwrap();
+                assert_eq!(encoded_point.as_bytes(), bytes);
+                assert_eq!(encoded_point.is_compressed(), compressed && bytes.len() > 1);
+
+                // Decoding with k256 and re-encoding must yield the same bytes.
+                let affine = k256::AffinePoint::from_encoded_point(&encoded_point).unwrap();
+                assert_eq!(affine.to_encoded_point(compressed).as_bytes(), bytes);
+            }
+        }
+    }
+
+    /// Test that the SEC1 encodings of the NIST curves round-trip with the encodings of the
+    /// RustCrypto crates, given a function that decodes and re-encodes with those.
+    #[test]
+    fn sec1_encoding_nist() {
+        fn check<C: WeierstrassCurve + std::fmt::Debug>(reencode: impl Fn(&[u8], bool) -> Vec<u8>) {
+            let mut rng = rand::thread_rng();
+            let points = (0..5).map(|_| CurvePoint::generator_mul(&Scalar::<C>::random(&mut rng)));
+
+            for point in points {
+                for compressed in [false, true] {
+                    let bytes = point.to_sec1(compressed);
+                    assert_eq!(CurvePoint::from_sec1(&bytes), Ok(point.clone()));
+                    assert_eq!(reencode(&bytes, compressed), bytes);
+                }
+            }
+        }
+
+        check::<P256>(|bytes, compressed| {
+            let encoded_point = p256::EncodedPoint::from_bytes(bytes).unwrap();
+            let affine = p256::AffinePoint::from_encoded_point(&encoded_point).unwrap();
+            affine.to_encoded_point(compressed).as_bytes().to_vec()
+        });
+        check::<P384>(|bytes, compressed| {
+            let encoded_point = p384::EncodedPoint::from_bytes(bytes).unwrap();
+            let affine = p384::AffinePoint::from_encoded_point(&encoded_point).unwrap();
+            affine.to_encoded_point(compressed).as_bytes().to_vec()
+        });
+        check::<P521>(|bytes, compressed| {
+            let encoded_point = p521::EncodedPoint::from_bytes(bytes).unwrap();
+            let affine = p521::AffinePoint::from_encoded_point(&encoded_point).unwrap();
+            affine.to_encoded_point(compressed).as_bytes().to_vec()
+        });
+    }
+
+    /// Test that coordinates with leading zero bytes keep their fixed width.
+    #[test]
+    fn sec1_encoding_is_fixed_width() {
+        let mut rng = rand::thread_rng();
+        let point = std::iter::repeat_with(|| {
+            CurvePoint::generator_mul(&Scalar::<Secp256k1>::random(&mut rng))
+        })
+        .find(|point| point.as_coordinates().unwrap().0.value().bits() <= 248)
+        .unwrap();
+
+        let bytes = point.to_sec1(false);
+        assert_eq!(bytes.len(), 65);
+        assert_eq!(bytes[1], 0);
+        assert_eq!(point.to_sec1(true).len(), 33);
+        assert_eq!(CurvePoint::from_sec1(&bytes), Ok(point));
+    }
+
+    #[test]
+    fn sec1_encoding_all_points_small_curves() {
+        for scalar in 0..83 {
+            let point = PrimeOrderTestCurve::generator() * &Scalar::new(scalar);
+            for compressed in [false, true] {
+                assert_eq!(
+                    CurvePoint::from_sec1(&point.to_sec1(compressed)),
+                    Ok(point.clone())
+                );
+            }
+        }
+
+        // TestCurve contains the point (2, 0), which only has an even compressed encoding.
+        for scalar in 0..12 {
+            let point = TestCurve::generator() * &BigInt::from(scalar);
+            assert_eq!(CurvePoint::from_sec1(&point.to_sec1(true)), Ok(point));
+        }
+        assert_eq!(
+            CurvePoint::<TestCurve>::from_sec1(&[0x03, 2]),
+            Err(PointError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn sec1_decoding_rejects_invalid_encodings() {
+        let bytes = Secp256k1::generator().to_sec1(false);
+        let field_modulus = Secp256k1::field_modulus().to_bytes_be().1;
+
+        let invalid_encodings = [
+            (vec![], PointError::InvalidEncoding),
+            (vec![0x00, 0x00], PointError::InvalidEncoding),
+            (vec![0x05; 65], PointError::InvalidEncoding),
+            (bytes[..64].to_vec(), PointError::InvalidEncoding),
+            (
+                Secp256k1::generator().to_sec1(true)[..32].to_vec(),
+                PointError::InvalidEncoding,
+            ),
+            (
+                [&[0x04][..], &field_modulus, &bytes[33..]].concat(),
+                PointError::CoordinateOutOfRange,
+            ),
+            (
+                [&[0x02][..], &field_modulus].concat(),
+                PointError::CoordinateOutOfRange,
+            ),
+            (
+                [&bytes[..64], &[bytes[64] ^ 1]].concat(),
+                PointError::NotOnCurve,
+            ),
+        ];
+
+        for (encoding, error) in invalid_encodings {
+            assert_eq!(CurvePoint::<Secp256k1>::from_sec1(&encoding), Err(error));
+        }
+
+        // x = 5 is not the x-coordinate of any point on secp256k1, since 5^3 + 7 is not a square.
+        let mut compressed = vec![0x02; 33];
+        compressed[1..].copy_from_slice(&FieldElement::<Secp256k1>::new(5).to_bytes_be());
+        assert_eq!(
+            CurvePoint::<Secp256k1>::from_sec1(&compressed),
+            Err(PointError::NotOnCurve)
+        );
+    }
+
+    /// Test that ECDH with the constant-time multiplication yields the same shared secret for both parties.
+    #[test]
+    fn constant_time_diffie_hellman() {
+        let mut rng = rand::thread_rng();
+        let sk1 = Scalar::<Secp256k1>::random(&mut rng);
+        let sk2 = Scalar::<Secp256k1>::random(&mut rng);
+
+        let pk1 = Secp256k1::generator().mul_ct(&sk1);
+        let pk2 = Secp256k1::generator().mul_ct(&sk2);
+
+        assert_eq!(pk1.mul_ct(&sk2), pk2.mul_ct(&sk1));
+        assert_eq!(pk1.mul_ct(&sk2), Secp256k1::generator() * &(sk1 * &sk2));
+    }
+
+    /// Test that the negation of `k * G` equals `(n - k) * G`.
+    #[test]
+    fn negation_matches_complementary_scalar() {
+        let scalar = BigInt::from_bytes_be(Sign::Plus, &rand::random::<[u8; 16]>());
+        let point = Secp256k1::generator() * &scalar;
+        let complement = Secp256k1::generator() * &(Secp256k1::order() - scalar);
+
+        assert_eq!(-point, complement);
+    }
+}
@@ -0,0 +1,532 @@
+use num::{bigint::Sign, BigInt};
+
+use crate::{
+    CurvePoint, FieldElement, Fp2, FrobeniusCoefficients, G2Point, GeneratorTable,
+    MontgomeryParameters, PairingCurve, PairingFamily, PointError, TwistType, WeierstrassCurve,
+};
+use once_cell::sync::Lazy;
+
+static GENERATOR: Lazy<CurvePoint<Bls12_381>> = Lazy::new(|| {
+    CurvePoint::new_unchecked(
+        BigInt::parse_bytes(
+            b"3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507",
+            10,
+        )
+        .unwrap(),
+        BigInt::parse_bytes(
+            b"1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569",
+            10,
+        )
+        .unwrap(),
+    )
+});
+
+static GENERATOR_TABLE: Lazy<GeneratorTable<Bls12_381>> =
+    Lazy::new(|| GeneratorTable::new(&GENERATOR));
+
+static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787",
+        10,
+    )
+    .unwrap()
+});
+
+static MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&FIELD_MODULUS));
+
+static ORDER: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"52435875175126190479447740508185965837690552500527637822603658699938581184513",
+        10,
+    )
+    .unwrap()
+});
+
+static G2_GENERATOR: Lazy<G2Point<Bls12_381>> = Lazy::new(|| {
+    let coordinate = |c0: &[u8], c1: &[u8]| {
+        Fp2::new(
+            FieldElement::new(BigInt::parse_bytes(c0, 10).unwrap()),
+            FieldElement::new(BigInt::parse_bytes(c1, 10).unwrap()),
+        )
+    };
+
+    G2Point::new_unchecked(
+        coordinate(
+            b"352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160",
+            b"3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758",
+        ),
+        coordinate(
+            b"1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905",
+            b"927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582",
+        ),
+    )
+});
+
+static FROBENIUS_COEFFICIENTS: Lazy<FrobeniusCoefficients<Bls12_381>> =
+    Lazy::new(FrobeniusCoefficients::new);
+
+/// Curve `BLS12-381` as defined in
+/// https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-pairing-friendly-curves-11#section-4.2.1.
+///
+/// Unlike [`Bn128`](crate::curves::Bn128), the curve has a cofactor, so points from untrusted
+/// sources must be checked with [`CurvePoint::is_in_subgroup`]. This is done when decoding
+/// points with [`CurvePoint::<Bls12_381>::from_compressed`] and the related functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bls12_381;
+
+impl WeierstrassCurve for Bls12_381 {
+    fn generator() -> CurvePoint<Self> {
+        GENERATOR.clone()
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &GENERATOR_TABLE
+    }
+
+    fn a() -> BigInt {
+        BigInt::ZERO
+    }
+
+    fn b() -> BigInt {
+        BigInt::from(4)
+    }
+
+    fn field_modulus() -> BigInt {
+        FIELD_MODULUS.clone()
+    }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        ORDER.clone()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::parse_bytes(b"396c8c005555e1568c00aaab0000aaab", 16).unwrap()
+    }
+}
+
+impl PairingCurve for Bls12_381 {
+    fn non_residue() -> Fp2<Self> {
+        Fp2::new(FieldElement::one(), FieldElement::one())
+    }
+
+    fn family() -> PairingFamily {
+        PairingFamily::Bls12
+    }
+
+    fn twist_type() -> TwistType {
+        TwistType::M
+    }
+
+    fn g2_generator() -> G2Point<Self> {
+        G2_GENERATOR.clone()
+    }
+
+    fn frobenius_coefficients() -> &'static FrobeniusCoefficients<Self> {
+        &FROBENIUS_COEFFICIENTS
+    }
+
+    fn ate_loop_count() -> BigInt {
+        // x = -0xd201000000010000.
+        -BigInt::from(0xd201_0000_0001_0000_u64)
+    }
+}
+
+/// Set in the first byte of compressed encodings.
+const COMPRESSION_FLAG: u8 = 0b1000_0000;
+/// Set in the first byte of encodings of the point at infinity.
+const INFINITY_FLAG: u8 = 0b0100_0000;
+/// Set in the first byte of compressed encodings if `y` is the lexicographically largest root.
+const SORT_FLAG: u8 = 0b0010_0000;
+
+/// Serialization in the format of the ZCash [`bls12_381`](https://docs.rs/bls12_381) crate,
+/// which is also used by Ethereum's consensus layer.
+///
+/// Coordinates are encoded as 48-byte big-endian integers, where the three most significant bits
+/// of the first byte carry flags: whether the encoding is compressed, whether the point is the
+/// point at infinity and, for compressed encodings, whether `y` is the lexicographically largest
+/// of its two possible values, i.e. larger than `-y`.
+impl CurvePoint<Bls12_381> {
+    /// Encodes the point as its x-coordinate and the flags.
+    pub fn to_compressed(&self) -> [u8; 48] {
+        encode(
+            self.as_coordinates()
+                .map(|(x, y)| (x.to_bytes_be(), is_lexicographically_largest(y))),
+            true,
+        )
+    }
+
+    /// Encodes the point as `x || y` and the flags.
+    pub fn to_uncompressed(&self) -> [u8; 96] {
+        encode(
+            self.as_coordinates()
+                .map(|(x, y)| ([x.to_bytes_be(), y.to_bytes_be()].concat(), false)),
+            false,
+        )
+    }
+
+    /// Decodes a point from its encoding produced by [`CurvePoint::<Bls12_381>::to_compressed`].
+    ///
+    /// Returns an error if the flags are invalid, the coordinate is not smaller than the field
+    /// modulus, there is no point with the given x-coordinate or the point is not in `G1`.
+    pub fn from_compressed(bytes: &[u8; 48]) -> Result<Self, PointError> {
+        let Some((x, sort)) = decode(bytes, true)? else {
+            return Ok(Self::point_at_infinity());
+        };
+
+        let x = FieldElement::from_bytes_be(&x)?;
+        let y = (x.square() * &x + &FieldElement::new(Bls12_381::b()))
+            .sqrt()
+            .ok_or(PointError::NotOnCurve)?;
+        let y = if is_lexicographically_largest(&y) == sort {
+            y
+        } else {
+            -y
+        };
+
+        Self::new_unchecked(x.value(), y.value()).check_subgroup()
+    }
+
+    /// Decodes a point from its encoding produced by [`CurvePoint::<Bls12_381>::to_uncompressed`].
+    ///
+    /// Returns an error if the flags are invalid, a coordinate is not smaller than the field
+    /// modulus, the point is not on the curve or not in `G1`.
+    pub fn from_uncompressed(bytes: &[u8; 96]) -> Result<Self, PointError> {
+        let Some((coordinates, _)) = decode(bytes, false)? else {
+            return Ok(Self::point_at_infinity());
+        };
+
+        let (x, y) = coordinates.split_at(48);
+        Self::try_new(
+            BigInt::from_bytes_be(Sign::Plus, x),
+            BigInt::from_bytes_be(Sign::Plus, y),
+        )?
+        .check_subgroup()
+    }
+
+    /// Returns `self` if it is in `G1`, or an error otherwise.
+    fn check_subgroup(self) -> Result<Self, PointError> {
+        if !self.is_in_subgroup() {
+            return Err(PointError::NotInSubgroup);
+        }
+
+        Ok(self)
+    }
+}
+
+/// Serialization in the format of the ZCash [`bls12_381`](https://docs.rs/bls12_381) crate,
+/// see [`CurvePoint::<Bls12_381>::to_compressed`].
+///
+/// An element `c0 + c1 * u` of `Fp2` is encoded as `c1 || c0`, with the flags in the first byte
+/// of `c1`. It is lexicographically larger than another element if its `c1` is larger, or if
+/// both `c1` are equal and its `c0` is larger.
+impl G2Point<Bls12_381> {
+    /// Encodes the point as its x-coordinate and the flags.
+    pub fn to_compressed(&self) -> [u8; 96] {
+        encode(
+            self.as_coordinates()
+                .map(|(x, y)| (fp2_to_bytes(x), is_lexicographically_largest_fp2(y))),
+            true,
+        )
+    }
+
+    /// Encodes the point as `x || y` and the flags.
+    pub fn to_uncompressed(&self) -> [u8; 192] {
+        encode(
+            self.as_coordinates()
+                .map(|(x, y)| ([fp2_to_bytes(x), fp2_to_bytes(y)].concat(), false)),
+            false,
+        )
+    }
+
+    /// Decodes a point from its encoding produced by [`G2Point::<Bls12_381>::to_compressed`].
+    ///
+    /// Returns an error if the flags are invalid, a coordinate is not smaller than the field
+    /// modulus, there is no point with the given x-coordinate or the point is not in `G2`.
+    pub fn from_compressed(bytes: &[u8; 96]) -> Result<Self, PointError> {
+        let Some((x, sort)) = decode(bytes, true)? else {
+            return Ok(Self::point_at_infinity());
+        };
+
+        let x = read_fp2(&x)?;
+        let y = (x.square() * &x + &Self::b())
+            .sqrt()
+            .ok_or(PointError::NotOnCurve)?;
+        let y = if is_lexicographically_largest_fp2(&y) == sort {
+            y
+        } else {
+            -y
+        };
+
+        Self::new_unchecked(x, y).check_subgroup()
+    }
+
+    /// Decodes a point from its encoding produced by [`G2Point::<Bls12_381>::to_uncompressed`].
+    ///
+    /// Returns an error if the flags are invalid, a coordinate is not smaller than the field
+    /// modulus, the point is not on the twist or not in `G2`.
+    pub fn from_uncompressed(bytes: &[u8; 192]) -> Result<Self, PointError> {
+        let Some((coordinates, _)) = decode(bytes, false)? else {
+            return Ok(Self::point_at_infinity());
+        };
+
+        let (x, y) = coordinates.split_at(96);
+        Self::try_new(read_fp2(x)?, read_fp2(y)?)?.check_subgroup()
+    }
+
+    /// Returns `self` if it is in `G2`, or an error otherwise.
+    fn check_subgroup(self) -> Result<Self, PointError> {
+        if !self.is_in_subgroup() {
+            return Err(PointError::NotInSubgroup);
+        }
+
+        Ok(self)
+    }
+}
+
+/// Encodes the coordinate bytes and whether the sort flag is set, or `None` for the point at
+/// infinity, together with the flags.
+fn encode<const N: usize>(coordinates: Option<(Vec<u8>, bool)>, compressed: bool) -> [u8; N] {
+    let mut bytes = [0; N];
+    match coordinates {
+        Some((coordinates, sort)) => {
+            bytes.copy_from_slice(&coordinates);
+            if sort {
+                bytes[0] |= SORT_FLAG;
+            }
+        }
+        None => bytes[0] |= INFINITY_FLAG,
+    }
+
+    if compressed {
+        bytes[0] |= COMPRESSION_FLAG;
+    }
+    bytes
+}
+
+/// Validates the flags of an encoding and returns the coordinate bytes without the flags and
+/// whether the sort flag is set, or `None` for the point at infinity.
+fn decode(bytes: &[u8], compressed: bool) -> Result<Option<(Vec<u8>, bool)>, PointError> {
+    let flags = bytes[0];
+    let sort = flags & SORT_FLAG != 0;
+
+    let mut coordinates = bytes.to_vec();
+    coordinates[0] &= !(COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG);
+
+    if (flags & COMPRESSION_FLAG != 0) != compressed || (sort && !compressed) {
+        return Err(PointError::InvalidEncoding);
+    }
+
+    if flags & INFINITY_FLAG != 0 {
+        // The point at infinity has a unique encoding.
+        if sort || coordinates.iter().any(|byte| *byte != 0) {
+            return Err(PointError::InvalidEncoding);
+        }
+        return Ok(None);
+    }
+
+    Ok(Some((coordinates, sort)))
+}
+
+/// Reads an element of `Fp2` encoded as `c1 || c0`.
+fn read_fp2(bytes: &[u8]) -> Result<Fp2<Bls12_381>, PointError> {
+    let (c1, c0) = bytes.split_at(48);
+    Ok(Fp2::new(
+        FieldElement::from_bytes_be(c0)?,
+        FieldElement::from_bytes_be(c1)?,
+    ))
+}
+
+/// Encodes an element of `Fp2` as `c1 || c0`.
+fn fp2_to_bytes(element: &Fp2<Bls12_381>) -> Vec<u8> {
+    [element.c1().to_bytes_be(), element.c0().to_bytes_be()].concat()
+}
+
+/// Returns `true` if `element` is larger than `-element`.
+fn is_lexicographically_largest(element: &FieldElement<Bls12_381>) -> bool {
+    element.value() > (Bls12_381::field_modulus() - 1) / 2
+}
+
+/// Returns `true` if `element` is lexicographically larger than `-element`.
+fn is_lexicographically_largest_fp2(element: &Fp2<Bls12_381>) -> bool {
+    if element.c1().is_zero() {
+        is_lexicographically_largest(element.c0())
+    } else {
+        is_lexicographically_largest(element.c1())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scalar;
+
+    use super::*;
+
+    /// Checks the serialization test vectors of the ZCash `bls12_381` crate, which encode the
+    /// multiples `0, G, 2G, ...` of the generator.
+    fn check_test_vectors<P: PartialEq + std::fmt::Debug, const N: usize>(
+        vectors: &[u8],
+        generator: P,
+        add: impl Fn(&P, &P) -> P,
+        encode: impl Fn(&P) -> [u8; N],
+        decode: impl Fn(&[u8; N]) -> Result<P, PointError>,
+        identity: P,
+    ) {
+        let mut point = identity;
+        for vector in vectors.chunks_exact(N) {
+            assert_eq!(encode(&point), vector);
+            assert_eq!(decode(vector.try_into().unwrap()).as_ref(), Ok(&point));
+            point = add(&point, &generator);
+        }
+    }
+
+    #[test]
+    fn g1_test_vectors() {
+        check_test_vectors(
+            include_bytes!("../../tests/fixtures/bls12_381_g1_compressed.dat"),
+            Bls12_381::generator(),
+            |p, q| p + q,
+            CurvePoint::to_compressed,
+            CurvePoint::from_compressed,
+            CurvePoint::point_at_infinity(),
+        );
+        check_test_vectors(
+            include_bytes!("../../tests/fixtures/bls12_381_g1_uncompressed.dat"),
+            Bls12_381::generator(),
+            |p, q| p + q,
+            CurvePoint::to_uncompressed,
+            CurvePoint::from_uncompressed,
+            CurvePoint::point_at_infinity(),
+        );
+    }
+
+    const G2_COMPRESSED: &[u8] = include_bytes!("../../tests/fixtures/bls12_381_g2_compressed.dat");
+    const G2_UNCOMPRESSED: &[u8] =
+        include_bytes!("../../tests/fixtures/bls12_381_g2_uncompressed.dat");
+
+    /// Checks the first `count` of the 1000 `G2` test vectors.
+    fn check_g2_test_vectors(count: usize) {
+        check_test_vectors(
+            &G2_COMPRESSED[..count * 96],
+            G2Point::generator(),
+            |p, q| p + q,
+            G2Point::to_compressed,
+            G2Point::from_compressed,
+            G2Point::point_at_infinity(),
+        );
+        check_test_vectors(
+            &G2_UNCOMPRESSED[..count * 192],
+            G2Point::generator(),
+            |p, q| p + q,
+            G2Point::to_uncompressed,
+            G2Point::from_uncompressed,
+            G2Point::point_at_infinity(),
+        );
+    }
+
+    /// Decoding checks the subgroup, which is slow in `G2`, so only the first 20 vectors are
+    /// checked by default.
+    #[test]
+    fn g2_test_vectors() {
+        check_g2_test_vectors(20);
+    }
+
+    #[test]
+    #[ignore = "slow, run with `cargo test --release -- --ignored`"]
+    fn all_g2_test_vectors() {
+        check_g2_test_vectors(1000);
+    }
+
+    #[test]
+    fn rejects_invalid_encodings() {
+        let generator = Bls12_381::generator().to_compressed();
+
+        // The compression flag must match the format.
+        let mut uncompressed = [0; 96];
+        uncompressed[..48].copy_from_slice(&generator);
+        assert_eq!(
+            CurvePoint::from_uncompressed(&uncompressed),
+            Err(PointError::InvalidEncoding)
+        );
+        let mut bytes = generator;
+        bytes[0] &= !COMPRESSION_FLAG;
+        assert_eq!(
+            CurvePoint::from_compressed(&bytes),
+            Err(PointError::InvalidEncoding)
+        );
+
+        // The point at infinity must not have other bits set.
+        let mut bytes = CurvePoint::<Bls12_381>::point_at_infinity().to_compressed();
+        bytes[47] = 1;
+        assert_eq!(
+            CurvePoint::from_compressed(&bytes),
+            Err(PointError::InvalidEncoding)
+        );
+        let mut bytes = CurvePoint::<Bls12_381>::point_at_infinity().to_compressed();
+        bytes[0] |= SORT_FLAG;
+        assert_eq!(
+            CurvePoint::from_compressed(&bytes),
+            Err(PointError::InvalidEncoding)
+        );
+
+        // The field modulus as the x-coordinate.
+        let (_, modulus) = Bls12_381::field_modulus().to_bytes_be();
+        let mut bytes = [0; 48];
+        bytes.copy_from_slice(&modulus);
+        bytes[0] |= COMPRESSION_FLAG;
+        assert_eq!(
+            CurvePoint::from_compressed(&bytes),
+            Err(PointError::CoordinateOutOfRange)
+        );
+    }
+
+    /// Most points on the curve are not in `G1` because of the cofactor.
+    #[test]
+    fn rejects_points_outside_of_subgroup() {
+        // x = 0 gives y^2 = 4, so (0, 2) is on the curve, but its order is 3.
+        let point = CurvePoint::<Bls12_381>::new_unchecked(0, 2);
+        assert!(point.is_on_curve());
+        assert!(!point.is_in_subgroup());
+        assert_eq!(
+            CurvePoint::from_compressed(&point.to_compressed()),
+            Err(PointError::NotInSubgroup)
+        );
+        assert_eq!(
+            CurvePoint::from_uncompressed(&point.to_uncompressed()),
+            Err(PointError::NotInSubgroup)
+        );
+
+        // Clearing the cofactor maps the point to the point at infinity.
+        assert_eq!(
+            &point * &Bls12_381::cofactor(),
+            CurvePoint::point_at_infinity()
+        );
+
+        // The first point on the twist with a small integer as its x-coordinate.
+        let point = (0..)
+            .find_map(|x| {
+                let x = Fp2::from(FieldElement::new(x));
+                let y = (x.square() * &x + &G2Point::<Bls12_381>::b()).sqrt()?;
+                Some(G2Point::new_unchecked(x, y))
+            })
+            .unwrap();
+        assert!(point.is_on_curve());
+        assert!(!point.is_in_subgroup());
+        assert_eq!(
+            G2Point::from_compressed(&point.to_compressed()),
+            Err(PointError::NotInSubgroup)
+        );
+        assert_eq!(
+            G2Point::from_uncompressed(&point.to_uncompressed()),
+            Err(PointError::NotInSubgroup)
+        );
+
+        let point = &G2Point::<Bls12_381>::generator() * &Scalar::new(42);
+        assert!(point.is_in_subgroup());
+        assert_eq!(G2Point::from_compressed(&point.to_compressed()), Ok(point));
+    }
+}
@@ -1,10 +1,15 @@
 use num::BigInt;
 
-use crate::{CurvePoint, WeierstrassCurve};
+use crate::{
+    CurvePoint, Endomorphism, FieldElement, Fp2, FrobeniusCoefficients, G2Point, GeneratorTable,
+    MontgomeryParameters, PairingCurve, PairingFamily, Scalar, TwistType, WeierstrassCurve,
+};
 use once_cell::sync::Lazy;
 
 static GENERATOR: Lazy<CurvePoint<Bn128>> =
-    Lazy::new(|| CurvePoint::new(BigInt::from(1), BigInt::from(2)));
+    Lazy::new(|| CurvePoint::new_unchecked(BigInt::from(1), BigInt::from(2)));
+
+static GENERATOR_TABLE: Lazy<GeneratorTable<Bn128>> = Lazy::new(|| GeneratorTable::new(&GENERATOR));
 
 static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
     BigInt::parse_bytes(
@@ -14,6 +19,55 @@ static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
     .unwrap()
 });
 
+static MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&FIELD_MODULUS));
+
+static ORDER: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10,
+    )
+    .unwrap()
+});
+
+static ENDOMORPHISM: Lazy<Endomorphism<Bn128>> = Lazy::new(|| {
+    let beta = BigInt::parse_bytes(
+        b"30644e72e131a0295e6dd9e7e0acccb0c28f069fbb966e3de4bd44e5607cfd48",
+        16,
+    )
+    .unwrap();
+    let lambda = BigInt::parse_bytes(
+        b"30644e72e131a029048b6e193fd84104cc37a73fec2bc5e9b8ca0b2d36636f23",
+        16,
+    )
+    .unwrap();
+
+    Endomorphism::new(FieldElement::new(beta), Scalar::new(lambda))
+});
+
+static G2_GENERATOR: Lazy<G2Point<Bn128>> = Lazy::new(|| {
+    let coordinate = |c0: &[u8], c1: &[u8]| {
+        Fp2::new(
+            FieldElement::new(BigInt::parse_bytes(c0, 10).unwrap()),
+            FieldElement::new(BigInt::parse_bytes(c1, 10).unwrap()),
+        )
+    };
+
+    G2Point::new_unchecked(
+        coordinate(
+            b"10857046999023057135944570762232829481370756359578518086990519993285655852781",
+            b"11559732032986387107991004021392285783925812861821192530917403151452391805634",
+        ),
+        coordinate(
+            b"8495653923123431417604973247489272438418190587263600148770280649306958101930",
+            b"4082367875863433681332203403145435568316851327593401208105741076214120093531",
+        ),
+    )
+});
+
+static FROBENIUS_COEFFICIENTS: Lazy<FrobeniusCoefficients<Bn128>> =
+    Lazy::new(FrobeniusCoefficients::new);
+
 /// Curve `bn128` as defined in https://eips.ethereum.org/EIPS/eip-197.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Bn128;
@@ -23,11 +77,62 @@ impl WeierstrassCurve for Bn128 {
         GENERATOR.clone()
     }
 
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &GENERATOR_TABLE
+    }
+
     fn a() -> BigInt {
         BigInt::ZERO
     }
 
+    fn b() -> BigInt {
+        BigInt::from(3)
+    }
+
     fn field_modulus() -> BigInt {
         FIELD_MODULUS.clone()
     }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        ORDER.clone()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+
+    fn endomorphism() -> Option<&'static Endomorphism<Self>> {
+        Some(&ENDOMORPHISM)
+    }
+}
+
+impl PairingCurve for Bn128 {
+    fn non_residue() -> Fp2<Self> {
+        Fp2::new(FieldElement::new(9), FieldElement::one())
+    }
+
+    fn family() -> PairingFamily {
+        PairingFamily::Bn
+    }
+
+    fn twist_type() -> TwistType {
+        TwistType::D
+    }
+
+    fn g2_generator() -> G2Point<Self> {
+        G2_GENERATOR.clone()
+    }
+
+    fn frobenius_coefficients() -> &'static FrobeniusCoefficients<Self> {
+        &FROBENIUS_COEFFICIENTS
+    }
+
+    fn ate_loop_count() -> BigInt {
+        // 6u + 2 for u = 4965661367192848881.
+        BigInt::parse_bytes(b"29793968203157093288", 10).unwrap()
+    }
 }
@@ -1,5 +1,17 @@
+mod bls12_381;
 mod bn128;
+mod p256;
+mod p384;
+mod p521;
 mod secp256k1;
+#[cfg(test)]
+mod test_curve;
 
+pub use bls12_381::*;
 pub use bn128::*;
+pub use p256::*;
+pub use p384::*;
+pub use p521::*;
 pub use secp256k1::*;
+#[cfg(test)]
+pub(crate) use test_curve::*;
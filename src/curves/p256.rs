@@ -0,0 +1,88 @@
+use num::BigInt;
+
+use crate::{CurvePoint, GeneratorTable, MontgomeryParameters, WeierstrassCurve};
+use once_cell::sync::Lazy;
+
+static GENERATOR: Lazy<CurvePoint<P256>> = Lazy::new(|| {
+    let x = BigInt::parse_bytes(
+        b"6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        16,
+    )
+    .unwrap();
+    let y = BigInt::parse_bytes(
+        b"4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        16,
+    )
+    .unwrap();
+
+    CurvePoint::new_unchecked(x, y)
+});
+
+static GENERATOR_TABLE: Lazy<GeneratorTable<P256>> = Lazy::new(|| GeneratorTable::new(&GENERATOR));
+
+static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        16,
+    )
+    .unwrap()
+});
+
+static MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&FIELD_MODULUS));
+
+static ORDER: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        16,
+    )
+    .unwrap()
+});
+
+static B: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+        16,
+    )
+    .unwrap()
+});
+
+/// Curve P-256, also known as secp256r1, with the parameters from
+/// <https://doi.org/10.6028/NIST.SP.800-186> as referenced by FIPS 186-5.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P256;
+
+impl WeierstrassCurve for P256 {
+    fn generator() -> CurvePoint<Self> {
+        GENERATOR.clone()
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &GENERATOR_TABLE
+    }
+
+    fn a() -> BigInt {
+        // a = -3 for all NIST prime curves.
+        FIELD_MODULUS.clone() - 3
+    }
+
+    fn b() -> BigInt {
+        B.clone()
+    }
+
+    fn field_modulus() -> BigInt {
+        FIELD_MODULUS.clone()
+    }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        ORDER.clone()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+}
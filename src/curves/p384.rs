@@ -0,0 +1,88 @@
+use num::BigInt;
+
+use crate::{CurvePoint, GeneratorTable, MontgomeryParameters, WeierstrassCurve};
+use once_cell::sync::Lazy;
+
+static GENERATOR: Lazy<CurvePoint<P384>> = Lazy::new(|| {
+    let x = BigInt::parse_bytes(
+        b"aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+        16,
+    )
+    .unwrap();
+    let y = BigInt::parse_bytes(
+        b"3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+        16,
+    )
+    .unwrap();
+
+    CurvePoint::new_unchecked(x, y)
+});
+
+static GENERATOR_TABLE: Lazy<GeneratorTable<P384>> = Lazy::new(|| GeneratorTable::new(&GENERATOR));
+
+static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+        16,
+    )
+    .unwrap()
+});
+
+static MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&FIELD_MODULUS));
+
+static ORDER: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+        16,
+    )
+    .unwrap()
+});
+
+static B: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+        16,
+    )
+    .unwrap()
+});
+
+/// Curve P-384, also known as secp384r1, with the parameters from
+/// <https://doi.org/10.6028/NIST.SP.800-186> as referenced by FIPS 186-5.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P384;
+
+impl WeierstrassCurve for P384 {
+    fn generator() -> CurvePoint<Self> {
+        GENERATOR.clone()
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &GENERATOR_TABLE
+    }
+
+    fn a() -> BigInt {
+        // a = -3 for all NIST prime curves.
+        FIELD_MODULUS.clone() - 3
+    }
+
+    fn b() -> BigInt {
+        B.clone()
+    }
+
+    fn field_modulus() -> BigInt {
+        FIELD_MODULUS.clone()
+    }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        ORDER.clone()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+}
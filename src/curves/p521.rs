@@ -0,0 +1,88 @@
+use num::BigInt;
+
+use crate::{CurvePoint, GeneratorTable, MontgomeryParameters, WeierstrassCurve};
+use once_cell::sync::Lazy;
+
+static GENERATOR: Lazy<CurvePoint<P521>> = Lazy::new(|| {
+    let x = BigInt::parse_bytes(
+        b"00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        16,
+    )
+    .unwrap();
+    let y = BigInt::parse_bytes(
+        b"011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+        16,
+    )
+    .unwrap();
+
+    CurvePoint::new_unchecked(x, y)
+});
+
+static GENERATOR_TABLE: Lazy<GeneratorTable<P521>> = Lazy::new(|| GeneratorTable::new(&GENERATOR));
+
+static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        16,
+    )
+    .unwrap()
+});
+
+static MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&FIELD_MODULUS));
+
+static ORDER: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+        16,
+    )
+    .unwrap()
+});
+
+static B: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+        16,
+    )
+    .unwrap()
+});
+
+/// Curve P-521, also known as secp521r1, with the parameters from
+/// <https://doi.org/10.6028/NIST.SP.800-186> as referenced by FIPS 186-5.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P521;
+
+impl WeierstrassCurve for P521 {
+    fn generator() -> CurvePoint<Self> {
+        GENERATOR.clone()
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &GENERATOR_TABLE
+    }
+
+    fn a() -> BigInt {
+        // a = -3 for all NIST prime curves.
+        FIELD_MODULUS.clone() - 3
+    }
+
+    fn b() -> BigInt {
+        B.clone()
+    }
+
+    fn field_modulus() -> BigInt {
+        FIELD_MODULUS.clone()
+    }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        ORDER.clone()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+}
@@ -1,6 +1,9 @@
 use num::BigInt;
 
-use crate::{CurvePoint, WeierstrassCurve};
+use crate::{
+    CurvePoint, Endomorphism, FieldElement, GeneratorTable, MontgomeryParameters, Scalar,
+    WeierstrassCurve,
+};
 use once_cell::sync::Lazy;
 
 static GENERATOR: Lazy<CurvePoint<Secp256k1>> = Lazy::new(|| {
@@ -15,9 +18,12 @@ static GENERATOR: Lazy<CurvePoint<Secp256k1>> = Lazy::new(|| {
     )
     .unwrap();
 
-    CurvePoint::new(x, y)
+    CurvePoint::new_unchecked(x, y)
 });
 
+static GENERATOR_TABLE: Lazy<GeneratorTable<Secp256k1>> =
+    Lazy::new(|| GeneratorTable::new(&GENERATOR));
+
 static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
     BigInt::parse_bytes(
         b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
@@ -26,6 +32,32 @@ static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| {
     .unwrap()
 });
 
+static MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&FIELD_MODULUS));
+
+static ORDER: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        16,
+    )
+    .unwrap()
+});
+
+static ENDOMORPHISM: Lazy<Endomorphism<Secp256k1>> = Lazy::new(|| {
+    let beta = BigInt::parse_bytes(
+        b"7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+        16,
+    )
+    .unwrap();
+    let lambda = BigInt::parse_bytes(
+        b"5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+        16,
+    )
+    .unwrap();
+
+    Endomorphism::new(FieldElement::new(beta), Scalar::new(lambda))
+});
+
 /// Curve secp256k1 as defined in <http://www.secg.org/sec2-v2.pdf>.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Secp256k1;
@@ -35,11 +67,35 @@ impl WeierstrassCurve for Secp256k1 {
         GENERATOR.clone()
     }
 
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &GENERATOR_TABLE
+    }
+
     fn a() -> BigInt {
         BigInt::ZERO
     }
 
+    fn b() -> BigInt {
+        BigInt::from(7)
+    }
+
     fn field_modulus() -> BigInt {
         FIELD_MODULUS.clone()
     }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        ORDER.clone()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+
+    fn endomorphism() -> Option<&'static Endomorphism<Self>> {
+        Some(&ENDOMORPHISM)
+    }
 }
@@ -0,0 +1,153 @@
+use num::BigInt;
+use once_cell::sync::Lazy;
+
+use crate::{CurvePoint, GeneratorTable, MontgomeryParameters, WeierstrassCurve};
+
+static GENERATOR: Lazy<CurvePoint<TestCurve>> =
+    Lazy::new(|| CurvePoint::new_unchecked(BigInt::from(4), BigInt::from(10)));
+static GENERATOR_TABLE: Lazy<GeneratorTable<TestCurve>> =
+    Lazy::new(|| GeneratorTable::new(&GENERATOR));
+static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| BigInt::from(11));
+static MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&FIELD_MODULUS));
+
+/// A test curve `y^2 = x^3 + 3` for initial testing with a small modulus.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct TestCurve;
+
+impl WeierstrassCurve for TestCurve {
+    fn generator() -> CurvePoint<Self>
+    where
+        Self: Sized,
+    {
+        GENERATOR.clone()
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &GENERATOR_TABLE
+    }
+
+    fn a() -> BigInt {
+        BigInt::ZERO
+    }
+
+    fn b() -> BigInt {
+        BigInt::from(3)
+    }
+
+    fn field_modulus() -> BigInt {
+        FIELD_MODULUS.clone()
+    }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        BigInt::from(12)
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+}
+
+static PRIME_ORDER_GENERATOR: Lazy<CurvePoint<PrimeOrderTestCurve>> =
+    Lazy::new(|| CurvePoint::new_unchecked(BigInt::from(0), BigInt::from(3)));
+static PRIME_ORDER_GENERATOR_TABLE: Lazy<GeneratorTable<PrimeOrderTestCurve>> =
+    Lazy::new(|| GeneratorTable::new(&PRIME_ORDER_GENERATOR));
+static PRIME_ORDER_FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| BigInt::from(101));
+static PRIME_ORDER_MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&PRIME_ORDER_FIELD_MODULUS));
+
+/// A test curve `y^2 = x^3 + 6x + 9` with a small modulus and prime order 83.
+/// Used to test formulas for curves with `a != 0` and formulas that require a prime order.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct PrimeOrderTestCurve;
+
+impl WeierstrassCurve for PrimeOrderTestCurve {
+    fn generator() -> CurvePoint<Self>
+    where
+        Self: Sized,
+    {
+        PRIME_ORDER_GENERATOR.clone()
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &PRIME_ORDER_GENERATOR_TABLE
+    }
+
+    fn a() -> BigInt {
+        BigInt::from(6)
+    }
+
+    fn b() -> BigInt {
+        BigInt::from(9)
+    }
+
+    fn field_modulus() -> BigInt {
+        PRIME_ORDER_FIELD_MODULUS.clone()
+    }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &PRIME_ORDER_MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        BigInt::from(83)
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+}
+
+static MOD17_GENERATOR: Lazy<CurvePoint<Mod17TestCurve>> =
+    Lazy::new(|| CurvePoint::new_unchecked(BigInt::from(5), BigInt::from(1)));
+static MOD17_GENERATOR_TABLE: Lazy<GeneratorTable<Mod17TestCurve>> =
+    Lazy::new(|| GeneratorTable::new(&MOD17_GENERATOR));
+static MOD17_FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| BigInt::from(17));
+static MOD17_MONTGOMERY_PARAMETERS: Lazy<MontgomeryParameters> =
+    Lazy::new(|| MontgomeryParameters::new(&MOD17_FIELD_MODULUS));
+
+/// A test curve `y^2 = x^3 + 2x + 2` with prime order 19 over a field with modulus
+/// `17 = 1 mod 4`. Used to exercise the general Tonelli-Shanks path of square roots.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Mod17TestCurve;
+
+impl WeierstrassCurve for Mod17TestCurve {
+    fn generator() -> CurvePoint<Self>
+    where
+        Self: Sized,
+    {
+        MOD17_GENERATOR.clone()
+    }
+
+    fn generator_table() -> &'static GeneratorTable<Self> {
+        &MOD17_GENERATOR_TABLE
+    }
+
+    fn a() -> BigInt {
+        BigInt::from(2)
+    }
+
+    fn b() -> BigInt {
+        BigInt::from(2)
+    }
+
+    fn field_modulus() -> BigInt {
+        MOD17_FIELD_MODULUS.clone()
+    }
+
+    fn montgomery_parameters() -> &'static MontgomeryParameters {
+        &MOD17_MONTGOMERY_PARAMETERS
+    }
+
+    fn order() -> BigInt {
+        BigInt::from(19)
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+}
@@ -0,0 +1,729 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use num::{traits::Euclid, BigInt};
+
+use crate::{
+    montgomery::{Limbs, MAX_LIMBS},
+    point_arithmetic::{self, CurveContext},
+    CurveError, MontgomeryParameters, PointError, PrimeFieldElement, WeierstrassCurve,
+};
+
+/// A Weierstrass curve `y^2 = x^3 + ax + b` whose parameters are only known at runtime,
+/// e.g. because they are loaded from a configuration file.
+///
+/// Unlike the curves in [`curves`](crate::curves), which implement [`WeierstrassCurve`] on a
+/// zero-sized type, the parameters are stored in the value and every [`DynamicPoint`] carries a
+/// reference to its curve. The points share the arithmetic of [`CurvePoint`](crate::CurvePoint),
+/// except for the precomputations that static curves keep in statics, i.e. the generator table
+/// and the GLV endomorphism.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicCurve {
+    field_modulus: BigInt,
+    montgomery_parameters: MontgomeryParameters,
+    a: BigInt,
+    b: BigInt,
+    generator: (BigInt, BigInt),
+    order: BigInt,
+    cofactor: BigInt,
+}
+
+impl DynamicCurve {
+    /// Creates the curve `y^2 = x^3 + ax + b` over the prime field with the given
+    /// `field_modulus`, with the `generator` of the given `order` and the `cofactor`.
+    ///
+    /// `a` and `b` are reduced modulo the field modulus. Returns an error if the field modulus
+    /// is not a prime larger than three with at most 576 bits, the curve is singular, the
+    /// generator is not on the curve, multiplying the generator with the order does not result
+    /// in the point at infinity, or `order * cofactor` is not a possible number of points on the
+    /// curve.
+    ///
+    /// The primality of the field modulus is checked with the Miller-Rabin test over a fixed set
+    /// of bases, which detects mistakes in the parameters but not composites crafted to pass it.
+    pub fn new(
+        field_modulus: BigInt,
+        a: BigInt,
+        b: BigInt,
+        generator: (BigInt, BigInt),
+        order: BigInt,
+        cofactor: BigInt,
+    ) -> Result<Self, CurveError> {
+        if field_modulus <= BigInt::from(3)
+            || field_modulus.bits() > 64 * MAX_LIMBS as u64
+            || !is_probable_prime(&field_modulus)
+        {
+            return Err(CurveError::InvalidFieldModulus);
+        }
+
+        let a = Euclid::rem_euclid(&a, &field_modulus);
+        let b = Euclid::rem_euclid(&b, &field_modulus);
+        let discriminant = BigInt::from(4) * &a * &a * &a + BigInt::from(27) * &b * &b;
+        if Euclid::rem_euclid(&discriminant, &field_modulus) == BigInt::ZERO {
+            return Err(CurveError::SingularCurve);
+        }
+
+        let curve = Self {
+            montgomery_parameters: MontgomeryParameters::new(&field_modulus),
+            field_modulus,
+            a,
+            b,
+            generator,
+            order,
+            cofactor,
+        };
+
+        let (x, y) = curve.generator.clone();
+        let generator = curve.point(x, y).map_err(CurveError::InvalidGenerator)?;
+
+        if curve.order <= BigInt::ZERO || (&generator * &curve.order).coordinates.is_some() {
+            return Err(CurveError::InvalidOrder);
+        }
+
+        // By Hasse's theorem, the number of points N satisfies |N - (p + 1)| <= 2 * sqrt(p).
+        let trace = &curve.order * &curve.cofactor - &curve.field_modulus - 1;
+        if curve.cofactor <= BigInt::ZERO
+            || &trace * &trace > BigInt::from(4) * &curve.field_modulus
+        {
+            return Err(CurveError::InvalidCofactor);
+        }
+
+        Ok(curve)
+    }
+
+    /// Creates the dynamic equivalent of the static curve `C`.
+    ///
+    /// The parameters of static curves are trusted and not validated again.
+    pub fn from_curve<C: WeierstrassCurve>() -> Self {
+        let generator = C::generator();
+        // SAFETY: The generator is never the point at infinity.
+        let (x, y) = generator.as_coordinates().unwrap();
+
+        Self {
+            field_modulus: C::field_modulus(),
+            montgomery_parameters: C::montgomery_parameters().clone(),
+            a: C::a(),
+            b: C::b(),
+            generator: (x.value(), y.value()),
+            order: C::order(),
+            cofactor: C::cofactor(),
+        }
+    }
+
+    /// Returns the field modulus of the curve.
+    pub fn field_modulus(&self) -> &BigInt {
+        &self.field_modulus
+    }
+
+    /// Returns the parameter `a` of the curve.
+    pub fn a(&self) -> &BigInt {
+        &self.a
+    }
+
+    /// Returns the parameter `b` of the curve.
+    pub fn b(&self) -> &BigInt {
+        &self.b
+    }
+
+    /// Returns the order `n` of the generator.
+    pub fn order(&self) -> &BigInt {
+        &self.order
+    }
+
+    /// Returns the cofactor `h` of the curve.
+    pub fn cofactor(&self) -> &BigInt {
+        &self.cofactor
+    }
+
+    /// Returns the generator point of the curve.
+    pub fn generator(&self) -> DynamicPoint<'_> {
+        let (x, y) = self.generator.clone();
+        self.point_unchecked(x, y)
+    }
+
+    /// Returns the point at infinity of the curve.
+    pub fn point_at_infinity(&self) -> DynamicPoint<'_> {
+        CurveContext::point(self, None)
+    }
+
+    /// Creates the point with the given coordinates on the curve.
+    ///
+    /// Returns an error if a coordinate is not in the range `[0, field_modulus)` or if the point
+    /// does not satisfy the curve equation, like [`CurvePoint::try_new`](crate::CurvePoint::try_new).
+    pub fn point(
+        &self,
+        x: impl Into<BigInt>,
+        y: impl Into<BigInt>,
+    ) -> Result<DynamicPoint<'_>, PointError> {
+        point_arithmetic::try_new(self, x.into(), y.into())
+    }
+
+    /// Decodes a point on the curve from its [SEC 1](https://www.secg.org/sec1-v2.pdf) encoding,
+    /// like [`CurvePoint::from_sec1`](crate::CurvePoint::from_sec1).
+    pub fn point_from_sec1(&self, bytes: &[u8]) -> Result<DynamicPoint<'_>, PointError> {
+        point_arithmetic::from_sec1(self, bytes)
+    }
+
+    /// Creates the point with the given coordinates without validating them.
+    fn point_unchecked(&self, x: BigInt, y: BigInt) -> DynamicPoint<'_> {
+        CurveContext::point(self, Some((self.element(x), self.element(y))))
+    }
+}
+
+impl<'c> CurveContext for &'c DynamicCurve {
+    type Element = DynamicFieldElement<'c>;
+    type Point = DynamicPoint<'c>;
+
+    fn element(self, value: BigInt) -> DynamicFieldElement<'c> {
+        let value = Euclid::rem_euclid(&value, &self.field_modulus);
+        DynamicFieldElement {
+            limbs: self.montgomery_parameters.encode(&value),
+            parameters: &self.montgomery_parameters,
+        }
+    }
+
+    fn zero(self) -> DynamicFieldElement<'c> {
+        DynamicFieldElement {
+            limbs: [0; MAX_LIMBS],
+            parameters: &self.montgomery_parameters,
+        }
+    }
+
+    fn one(self) -> DynamicFieldElement<'c> {
+        DynamicFieldElement {
+            limbs: self.montgomery_parameters.one(),
+            parameters: &self.montgomery_parameters,
+        }
+    }
+
+    fn a(self) -> DynamicFieldElement<'c> {
+        self.element(self.a.clone())
+    }
+
+    fn b(self) -> DynamicFieldElement<'c> {
+        self.element(self.b.clone())
+    }
+
+    fn field_modulus(self) -> BigInt {
+        self.field_modulus.clone()
+    }
+
+    fn order(self) -> BigInt {
+        self.order.clone()
+    }
+
+    fn point(
+        self,
+        coordinates: Option<(DynamicFieldElement<'c>, DynamicFieldElement<'c>)>,
+    ) -> DynamicPoint<'c> {
+        DynamicPoint {
+            curve: self,
+            coordinates,
+        }
+    }
+
+    fn coordinates<'p>(
+        point: &'p DynamicPoint<'c>,
+    ) -> Option<(&'p DynamicFieldElement<'c>, &'p DynamicFieldElement<'c>)> {
+        point.coordinates.as_ref().map(|(x, y)| (x, y))
+    }
+}
+
+/// An element of the prime field of a [`DynamicCurve`], which carries a reference to the curve's
+/// [`MontgomeryParameters`] instead of looking them up through a [`WeierstrassCurve`] like
+/// [`FieldElement`](crate::FieldElement).
+#[derive(Clone, Copy)]
+pub(crate) struct DynamicFieldElement<'c> {
+    limbs: Limbs,
+    parameters: &'c MontgomeryParameters,
+}
+
+impl Debug for DynamicFieldElement<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DynamicFieldElement")
+            .field(&self.value())
+            .finish()
+    }
+}
+
+impl PartialEq for DynamicFieldElement<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        // The Montgomery form is unique, since it is fully reduced.
+        self.limbs == other.limbs
+    }
+}
+
+impl Eq for DynamicFieldElement<'_> {}
+
+impl PrimeFieldElement for DynamicFieldElement<'_> {
+    fn parameters(&self) -> &MontgomeryParameters {
+        self.parameters
+    }
+
+    fn limbs(&self) -> &Limbs {
+        &self.limbs
+    }
+
+    fn with_limbs(&self, limbs: Limbs) -> Self {
+        Self {
+            limbs,
+            parameters: self.parameters,
+        }
+    }
+}
+
+impl<'c> Add<&DynamicFieldElement<'c>> for &DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn add(self, rhs: &DynamicFieldElement<'c>) -> Self::Output {
+        self.with_limbs(self.parameters.add(&self.limbs, &rhs.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Add<&DynamicFieldElement<'c>> for DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn add(self, rhs: &DynamicFieldElement<'c>) -> Self::Output {
+        self.with_limbs(self.parameters.add(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl<'c> Sub<&DynamicFieldElement<'c>> for &DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn sub(self, rhs: &DynamicFieldElement<'c>) -> Self::Output {
+        self.with_limbs(self.parameters.sub(&self.limbs, &rhs.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Sub<&DynamicFieldElement<'c>> for DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn sub(self, rhs: &DynamicFieldElement<'c>) -> Self::Output {
+        self.with_limbs(self.parameters.sub(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl<'c> Mul<&DynamicFieldElement<'c>> for &DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn mul(self, rhs: &DynamicFieldElement<'c>) -> Self::Output {
+        self.with_limbs(self.parameters.mul(&self.limbs, &rhs.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Mul<&DynamicFieldElement<'c>> for DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn mul(self, rhs: &DynamicFieldElement<'c>) -> Self::Output {
+        self.with_limbs(self.parameters.mul(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl<'c> Neg for &DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn neg(self) -> Self::Output {
+        self.with_limbs(self.parameters.neg(&self.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Neg for DynamicFieldElement<'c> {
+    type Output = DynamicFieldElement<'c>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+/// A point on a [`DynamicCurve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicPoint<'c> {
+    curve: &'c DynamicCurve,
+    coordinates: Option<(DynamicFieldElement<'c>, DynamicFieldElement<'c>)>,
+}
+
+impl<'c> DynamicPoint<'c> {
+    /// Returns the curve the point is on.
+    pub fn curve(&self) -> &'c DynamicCurve {
+        self.curve
+    }
+
+    /// Returns the point's x, y coordinates in that order or `None` if it's the point at infinity.
+    pub fn coordinates(&self) -> Option<(BigInt, BigInt)> {
+        self.coordinates
+            .as_ref()
+            .map(|(x, y)| (x.value(), y.value()))
+    }
+
+    /// Encodes the point as defined in Section 2.3.3 of [SEC 1](https://www.secg.org/sec1-v2.pdf),
+    /// like [`CurvePoint::to_sec1`](crate::CurvePoint::to_sec1).
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        point_arithmetic::to_sec1::<&DynamicCurve>(self, compressed)
+    }
+
+    /// Returns `true` if the point satisfies the curve equation `y^2 = x^3 + ax + b`.
+    ///
+    /// The point at infinity is always on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        point_arithmetic::is_on_curve(self.curve, self)
+    }
+
+    /// Returns the inverse `inv` of `self` such that `self` + `inv` equals the point at infinity.
+    pub fn negate(&self) -> DynamicPoint<'c> {
+        point_arithmetic::negate(self.curve, self)
+    }
+
+    /// Multiplies `self` with the public `scalar` using its width-`window` non-adjacent form,
+    /// like [`CurvePoint::mul_wnaf`](crate::CurvePoint::mul_wnaf).
+    ///
+    /// The scalar is reduced modulo the order of the generator first, so this is only correct
+    /// for points in the subgroup generated by it. The running time depends on the scalar, so do
+    /// not use this for secret scalars.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `window` is not in the range `[2, 16]`.
+    pub fn mul_wnaf(&self, scalar: &BigInt, window: usize) -> DynamicPoint<'c> {
+        let scalar = Euclid::rem_euclid(scalar, &self.curve.order);
+        point_arithmetic::mul_wnaf(self.curve, self, &scalar, window)
+    }
+
+    /// Multiplies `self` with the secret `scalar` using a Montgomery ladder,
+    /// like [`CurvePoint::mul_ct`](crate::CurvePoint::mul_ct).
+    ///
+    /// The scalar is reduced modulo the order of the generator first, so this is only correct
+    /// for points in the subgroup generated by it. The result is only guaranteed to be correct on
+    /// curves of prime order.
+    pub fn mul_ct(&self, scalar: &BigInt) -> DynamicPoint<'c> {
+        let scalar = Euclid::rem_euclid(scalar, &self.curve.order);
+        point_arithmetic::mul_ct(self.curve, self, &scalar)
+    }
+
+    /// Adds `q` to `self` on the elliptic curve.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the points are on different curves.
+    fn add(&self, q: &DynamicPoint<'c>) -> DynamicPoint<'c> {
+        assert!(
+            std::ptr::eq(self.curve, q.curve) || self.curve == q.curve,
+            "cannot add points on different curves"
+        );
+
+        point_arithmetic::add(self.curve, self, q)
+    }
+
+    /// Subtracts `q` from `self` on the elliptic curve, i.e. computes `self + (-q)`.
+    fn subtract(&self, q: &DynamicPoint<'c>) -> DynamicPoint<'c> {
+        DynamicPoint::add(self, &q.negate())
+    }
+
+    /// Multiplies `scalar` with `self`, see [`CurvePoint`](crate::CurvePoint)'s multiplication
+    /// with a [`BigInt`].
+    fn multiply(&self, scalar: &BigInt) -> DynamicPoint<'c> {
+        point_arithmetic::multiply(self.curve, self, scalar)
+    }
+}
+
+/// ## Panics
+///
+/// Panics if the points are on different curves.
+impl<'c> Add<&DynamicPoint<'c>> for &DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn add(self, q: &DynamicPoint<'c>) -> Self::Output {
+        DynamicPoint::add(self, q)
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Add<&DynamicPoint<'c>> for DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn add(self, q: &DynamicPoint<'c>) -> Self::Output {
+        DynamicPoint::add(&self, q)
+    }
+}
+
+/// ## Panics
+///
+/// Panics if the points are on different curves.
+impl<'c> Sub<&DynamicPoint<'c>> for &DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn sub(self, q: &DynamicPoint<'c>) -> Self::Output {
+        DynamicPoint::subtract(self, q)
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Sub<&DynamicPoint<'c>> for DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn sub(self, q: &DynamicPoint<'c>) -> Self::Output {
+        DynamicPoint::subtract(&self, q)
+    }
+}
+
+impl<'c> Neg for &DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn neg(self) -> Self::Output {
+        DynamicPoint::negate(self)
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Neg for DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn neg(self) -> Self::Output {
+        DynamicPoint::negate(&self)
+    }
+}
+
+impl<'c> Mul<&BigInt> for &DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn mul(self, scalar: &BigInt) -> Self::Output {
+        DynamicPoint::multiply(self, scalar)
+    }
+}
+
+// Additional implementation for convenience.
+impl<'c> Mul<&BigInt> for DynamicPoint<'c> {
+    type Output = DynamicPoint<'c>;
+
+    fn mul(self, scalar: &BigInt) -> Self::Output {
+        DynamicPoint::multiply(&self, scalar)
+    }
+}
+
+/// Returns `true` if `n` passes the Miller-Rabin test for the first twelve primes as bases.
+///
+/// This is exact for `n < 3.3 * 10^24`. Larger composites that pass the test for all of these
+/// bases exist, but are extremely unlikely to occur by accident.
+fn is_probable_prime(n: &BigInt) -> bool {
+    const BASES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < &BigInt::from(2) {
+        return false;
+    }
+
+    for base in BASES {
+        let base = BigInt::from(base);
+        if n == &base {
+            return true;
+        }
+        if Euclid::rem_euclid(n, &base) == BigInt::ZERO {
+            return false;
+        }
+    }
+
+    // Write n - 1 = d * 2^s with odd d.
+    let n_minus_one: BigInt = n - 1;
+    // SAFETY: n - 1 is positive, since n > 37.
+    let s = n_minus_one.trailing_zeros().unwrap();
+    let d = &n_minus_one >> s;
+
+    BASES.into_iter().all(|base| {
+        let mut x = BigInt::from(base).modpow(&d, n);
+        if x == BigInt::from(1) || x == n_minus_one {
+            return true;
+        }
+
+        for _ in 1..s {
+            x = Euclid::rem_euclid(&(&x * &x), n);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        curves::{Bls12_381, PrimeOrderTestCurve, Secp256k1, TestCurve, P256},
+        CurvePoint, Scalar,
+    };
+
+    use super::*;
+
+    /// Test that the dynamic curve built from the parameters of a static curve is valid and
+    /// computes the same results.
+    #[test]
+    fn matches_static_curves() {
+        fn check<C: WeierstrassCurve>() {
+            let (x, y) = C::generator()
+                .as_coordinates()
+                .map(|(x, y)| (x.value(), y.value()))
+                .unwrap();
+            let curve = DynamicCurve::new(
+                C::field_modulus(),
+                C::a(),
+                C::b(),
+                (x, y),
+                C::order(),
+                C::cofactor(),
+            )
+            .unwrap();
+            assert_eq!(curve, DynamicCurve::from_curve::<C>());
+
+            let to_dynamic = |point: &CurvePoint<C>| match point.as_coordinates() {
+                Some((x, y)) => curve.point(x.value(), y.value()).unwrap(),
+                None => curve.point_at_infinity(),
+            };
+
+            let scalar = BigInt::from(rand::random::<u128>());
+            let p = C::generator() * &BigInt::from(12345);
+            let q = C::generator() * &BigInt::from(-678);
+            let (dynamic_p, dynamic_q) = (to_dynamic(&p), to_dynamic(&q));
+
+            assert_eq!(to_dynamic(&C::generator()), curve.generator());
+            assert_eq!(&dynamic_p + &dynamic_q, to_dynamic(&(&p + &q)));
+            assert_eq!(&dynamic_p - &dynamic_q, to_dynamic(&(&p - &q)));
+            assert_eq!(&dynamic_p + &dynamic_p, to_dynamic(&(&p + &p)));
+            assert_eq!(&dynamic_p - &dynamic_p, curve.point_at_infinity());
+            assert_eq!(-&dynamic_q, to_dynamic(&-&q));
+            assert_eq!(&dynamic_p * &scalar, to_dynamic(&(&p * &scalar)));
+
+            let reduced_scalar = Scalar::new(scalar.clone());
+            assert_eq!(
+                dynamic_p.mul_wnaf(&scalar, 4),
+                to_dynamic(&p.mul_wnaf(&reduced_scalar, 4))
+            );
+            assert_eq!(
+                dynamic_p.mul_ct(&scalar),
+                to_dynamic(&p.mul_ct(&reduced_scalar))
+            );
+
+            for compressed in [false, true] {
+                let encoded = p.to_sec1(compressed);
+                assert_eq!(dynamic_p.to_sec1(compressed), encoded);
+                assert_eq!(curve.point_from_sec1(&encoded), Ok(dynamic_p.clone()));
+            }
+        }
+
+        check::<TestCurve>();
+        check::<PrimeOrderTestCurve>();
+        check::<Secp256k1>();
+        check::<P256>();
+        check::<Bls12_381>();
+    }
+
+    #[test]
+    fn curve_from_runtime_parameters() {
+        // y^2 = x^3 - 95x + 9 over F_101, which is the same curve as y^2 = x^3 + 6x + 9.
+        let curve = DynamicCurve::new(
+            101.into(),
+            (-95).into(),
+            9.into(),
+            (0.into(), 3.into()),
+            83.into(),
+            1.into(),
+        )
+        .unwrap();
+        assert_eq!(curve.a(), &BigInt::from(6));
+        assert_eq!(
+            curve.generator().coordinates(),
+            Some((BigInt::ZERO, BigInt::from(3)))
+        );
+
+        let generator = curve.generator();
+        let mut point = curve.point_at_infinity();
+        for scalar in 1..83 {
+            point = point + &generator;
+            assert!(point.is_on_curve());
+            assert_ne!(point, curve.point_at_infinity());
+            assert_eq!(point, &generator * &BigInt::from(scalar));
+        }
+        assert_eq!(point + &generator, curve.point_at_infinity());
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let new = |p: i64, a: i64, (x, y): (i64, i64), n: i64, h: i64| {
+            DynamicCurve::new(
+                p.into(),
+                a.into(),
+                9.into(),
+                (x.into(), y.into()),
+                n.into(),
+                h.into(),
+            )
+        };
+
+        assert!(new(101, 6, (0, 3), 83, 1).is_ok());
+        for p in [3, 100, 561] {
+            assert_eq!(
+                new(p, 6, (0, 3), 83, 1),
+                Err(CurveError::InvalidFieldModulus)
+            );
+        }
+        // The Mersenne prime 2^607 - 1 needs more limbs than the field arithmetic supports.
+        assert_eq!(
+            DynamicCurve::new(
+                (BigInt::from(1) << 607) - 1,
+                6.into(),
+                9.into(),
+                (0.into(), 3.into()),
+                83.into(),
+                1.into(),
+            ),
+            Err(CurveError::InvalidFieldModulus)
+        );
+        // 4 * (-3)^3 + 27 * 9^2 = 2079 = 3^3 * 7 * 11.
+        assert_eq!(new(11, -3, (0, 3), 12, 1), Err(CurveError::SingularCurve));
+        assert_eq!(
+            new(101, 6, (0, 4), 83, 1),
+            Err(CurveError::InvalidGenerator(PointError::NotOnCurve))
+        );
+        assert_eq!(
+            new(101, 6, (101, 3), 83, 1),
+            Err(CurveError::InvalidGenerator(
+                PointError::CoordinateOutOfRange
+            ))
+        );
+        for n in [0, 82, -83] {
+            assert_eq!(new(101, 6, (0, 3), n, 1), Err(CurveError::InvalidOrder));
+        }
+        for h in [0, 2] {
+            assert_eq!(new(101, 6, (0, 3), 83, h), Err(CurveError::InvalidCofactor));
+        }
+    }
+
+    #[test]
+    fn primality_test() {
+        let primes = (2..200)
+            .filter(|n| is_probable_prime(&BigInt::from(*n)))
+            .collect::<Vec<_>>();
+        let expected = (2..200)
+            .filter(|n: &i32| (2..*n).all(|d| n % d != 0))
+            .collect::<Vec<_>>();
+        assert_eq!(primes, expected);
+
+        assert!(is_probable_prime(&Secp256k1::field_modulus()));
+        assert!(is_probable_prime(&P256::order()));
+        // The Carmichael number 561 = 3 * 11 * 17 and a product of two large primes.
+        assert!(!is_probable_prime(&BigInt::from(561)));
+        assert!(!is_probable_prime(&(Secp256k1::order() * P256::order())));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add points on different curves")]
+    fn adding_points_on_different_curves_panics() {
+        let secp256k1 = DynamicCurve::from_curve::<Secp256k1>();
+        let p256 = DynamicCurve::from_curve::<P256>();
+
+        let _ = secp256k1.generator() + &p256.generator();
+    }
+}
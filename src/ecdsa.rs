@@ -0,0 +1,670 @@
+//! The Elliptic Curve Digital Signature Algorithm (ECDSA) as specified in Section 4.1 of
+//! [SEC 1](https://www.secg.org/sec1-v2.pdf), generic over any [`WeierstrassCurve`].
+//!
+//! The message is not hashed by this module. Callers pass the hash of the message,
+//! e.g. its SHA-256 digest, which is truncated to the bit length of the group order.
+
+use hmac::digest::{core_api::BlockSizeUser, Digest};
+use rand::RngCore;
+use sha2::Sha256;
+
+use crate::{
+    rfc6979::bits_to_int, CurvePoint, FieldElement, NonceGenerator, Scalar, WeierstrassCurve,
+};
+
+/// An ECDSA signature `(r, s)` with both components in the range `[1, n)`.
+#[derive(Debug)]
+pub struct Signature<C: WeierstrassCurve> {
+    r: Scalar<C>,
+    s: Scalar<C>,
+}
+
+impl<C: WeierstrassCurve> Clone for Signature<C> {
+    fn clone(&self) -> Self {
+        Self {
+            r: self.r.clone(),
+            s: self.s.clone(),
+        }
+    }
+}
+
+impl<C: WeierstrassCurve> PartialEq for Signature<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.s == other.s
+    }
+}
+
+impl<C: WeierstrassCurve> Eq for Signature<C> {}
+
+impl<C: WeierstrassCurve> Signature<C> {
+    /// Creates a signature from its components or returns `None` if one of them is zero.
+    pub fn new(r: Scalar<C>, s: Scalar<C>) -> Option<Self> {
+        if r.is_zero() || s.is_zero() {
+            return None;
+        }
+
+        Some(Self { r, s })
+    }
+
+    /// Returns the `r` component of the signature.
+    pub fn r(&self) -> &Scalar<C> {
+        &self.r
+    }
+
+    /// Returns the `s` component of the signature.
+    pub fn s(&self) -> &Scalar<C> {
+        &self.s
+    }
+
+    /// Returns `true` if `s` is at most `n / 2`.
+    pub fn is_low_s(&self) -> bool {
+        self.s.value() <= &(C::order() >> 1)
+    }
+
+    /// Returns the signature with `s` replaced by `n - s` if `s` is greater than `n / 2`.
+    ///
+    /// Both `(r, s)` and `(r, n - s)` are valid signatures for the same message, so anyone can
+    /// turn a valid signature into a different valid one. Protocols that must not accept such
+    /// malleated signatures, like Bitcoin and Ethereum, require `s` to be low.
+    pub fn normalize_s(&self) -> Self {
+        if self.is_low_s() {
+            return self.clone();
+        }
+
+        Self {
+            r: self.r.clone(),
+            s: -&self.s,
+        }
+    }
+
+    /// Returns the encoding `r || s` of the signature, with both components left-padded with
+    /// zeros to the byte length of the group order.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.r.to_bytes_be(), self.s.to_bytes_be()].concat()
+    }
+
+    /// Decodes a signature from its `r || s` encoding, see [`Signature::to_bytes`].
+    ///
+    /// Returns `None` if the length is wrong or a component is zero or not smaller than
+    /// the group order.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 2 * Scalar::<C>::byte_len() {
+            return None;
+        }
+
+        let (r, s) = bytes.split_at(Scalar::<C>::byte_len());
+        Self::new(Scalar::from_bytes_be(r)?, Scalar::from_bytes_be(s)?)
+    }
+}
+
+/// Signs the `message_hash` with the `secret` key, using a deterministic nonce derived
+/// as specified in RFC 6979 with HMAC-SHA-256.
+///
+/// The nonce is multiplied with the generator using the
+/// [constant-time ladder](CurvePoint::mul_ct). The resulting `s` is not normalized,
+/// see [`Signature::normalize_s`].
+///
+/// ## Panics
+///
+/// Panics if `secret` is zero.
+pub fn sign<C: WeierstrassCurve>(secret: &Scalar<C>, message_hash: &[u8]) -> Signature<C> {
+    sign_deterministic::<C, Sha256>(secret, message_hash)
+}
+
+/// Like [`sign`], but derives the nonce with the hash function `D`,
+/// which should be the hash function that produced `message_hash`.
+///
+/// See [`NonceGenerator`] for details.
+pub fn sign_deterministic<C: WeierstrassCurve, D: Digest + BlockSizeUser>(
+    secret: &Scalar<C>,
+    message_hash: &[u8],
+) -> Signature<C> {
+    sign_deterministic_with_recovery_id::<C, D>(secret, message_hash).0
+}
+
+/// Like [`sign`], but normalizes `s` and additionally returns the recovery id, which allows
+/// [recovering](recover_public_key) the public key from the signature and the message hash.
+pub fn sign_recoverable<C: WeierstrassCurve>(
+    secret: &Scalar<C>,
+    message_hash: &[u8],
+) -> (Signature<C>, u8) {
+    let (signature, recovery_id) =
+        sign_deterministic_with_recovery_id::<C, Sha256>(secret, message_hash);
+
+    if signature.is_low_s() {
+        (signature, recovery_id)
+    } else {
+        // Negating s corresponds to negating R, which flips the parity of its y-coordinate.
+        (signature.normalize_s(), recovery_id ^ 1)
+    }
+}
+
+fn sign_deterministic_with_recovery_id<C: WeierstrassCurve, D: Digest + BlockSizeUser>(
+    secret: &Scalar<C>,
+    message_hash: &[u8],
+) -> (Signature<C>, u8) {
+    assert!(!secret.is_zero(), "secret key must not be zero");
+
+    let hash = hash_to_scalar::<C>(message_hash);
+    NonceGenerator::<D>::new(secret.value(), message_hash, &C::order())
+        .find_map(|nonce| sign_with_nonce(secret, &hash, &Scalar::new(nonce)))
+        // SAFETY: The generator yields an infinite sequence of nonces.
+        .unwrap()
+}
+
+/// Like [`sign`], but draws the nonce from `rng`.
+///
+/// The security of the secret key depends entirely on the nonce: if a nonce is ever reused
+/// or partially predictable, the secret key can be recovered from the signatures.
+/// Prefer the deterministic nonces of [`sign`] unless randomized signatures are required.
+pub fn sign_with_rng<C: WeierstrassCurve, R: RngCore + ?Sized>(
+    secret: &Scalar<C>,
+    message_hash: &[u8],
+    rng: &mut R,
+) -> Signature<C> {
+    assert!(!secret.is_zero(), "secret key must not be zero");
+
+    let hash = hash_to_scalar::<C>(message_hash);
+    loop {
+        // The chance that a nonce fails is negligible for cryptographic curves,
+        // but not for small test curves.
+        if let Some((signature, _)) = sign_with_nonce(secret, &hash, &Scalar::random(rng)) {
+            return signature;
+        }
+    }
+}
+
+/// Signs the already converted `hash` with the `secret` key and the given `nonce`
+/// and returns the signature together with its recovery id.
+///
+/// Returns `None` if `r` or `s` is zero, in which case a different nonce must be used.
+fn sign_with_nonce<C: WeierstrassCurve>(
+    secret: &Scalar<C>,
+    hash: &Scalar<C>,
+    nonce: &Scalar<C>,
+) -> Option<(Signature<C>, u8)> {
+    // r = x(kG) mod n
+    let point = C::generator().mul_ct(nonce);
+    let (x, y) = point.as_coordinates()?;
+    let r = Scalar::new(x.value());
+
+    // s = k^-1 (e + r * d) mod n
+    let s = nonce.inverse()? * &(hash + &(&r * secret));
+
+    let recovery_id = u8::from(y.value().bit(0)) | u8::from(&x.value() != r.value()) << 1;
+    Some((Signature::new(r, s)?, recovery_id))
+}
+
+/// Verifies that `signature` is a valid signature of `message_hash` for the `public` key.
+///
+/// Both low and high `s` values are accepted. Callers that require low `s` values should
+/// additionally check [`Signature::is_low_s`].
+pub fn verify<C: WeierstrassCurve>(
+    public: &CurvePoint<C>,
+    message_hash: &[u8],
+    signature: &Signature<C>,
+) -> bool {
+    if *public == CurvePoint::point_at_infinity() || !public.is_on_curve() {
+        return false;
+    }
+
+    let hash = hash_to_scalar::<C>(message_hash);
+    // s is non-zero by construction, but only invertible if it is coprime to the order, which
+    // is not guaranteed for curves with a composite order.
+    let Some(s_inverse) = signature.s.inverse() else {
+        return false;
+    };
+    let u1 = &hash * &s_inverse;
+    let u2 = &signature.r * &s_inverse;
+
+    // R = u1 * G + u2 * Q
+    let point = CurvePoint::generator_mul(&u1) + &(public * &u2);
+    let Some((x, _)) = point.as_coordinates() else {
+        return false;
+    };
+
+    Scalar::new(x.value()) == signature.r
+}
+
+/// Recovers the public key that created `signature` for `message_hash`, as described in
+/// Section 4.1.6 of SEC 1.
+///
+/// Up to four public keys are valid for a signature. The `recovery_id` selects one of them:
+/// bit 0 is the parity of the y-coordinate of the point `R` with `x(R) mod n = r`, and bit 1 is
+/// set if `x(R) = r + n` rather than `r`. The latter only happens with negligible probability
+/// on curves whose order is slightly smaller than the field modulus.
+///
+/// Returns `None` if the recovery id is greater than 3, if no point `R` exists for it,
+/// if the signature has a high `s` value, since recovering from a malleated signature
+/// would yield a different public key, or if `r` is not invertible modulo the group order.
+pub fn recover_public_key<C: WeierstrassCurve>(
+    message_hash: &[u8],
+    signature: &Signature<C>,
+    recovery_id: u8,
+) -> Option<CurvePoint<C>> {
+    if recovery_id > 3 || !signature.is_low_s() {
+        return None;
+    }
+
+    // Decompress R from its x-coordinate and the parity of its y-coordinate.
+    let mut x = signature.r.value().clone();
+    if recovery_id & 2 != 0 {
+        x += C::order();
+    }
+    if x >= C::field_modulus() {
+        return None;
+    }
+    let prefix = 0x02 | (recovery_id & 1);
+    let point_r = CurvePoint::<C>::from_sec1(
+        &[&[prefix][..], &FieldElement::<C>::new(x).to_bytes_be()].concat(),
+    )
+    .ok()?;
+
+    // Q = r^-1 (sR - eG)
+    let hash = hash_to_scalar::<C>(message_hash);
+    let r_inverse = signature.r.inverse()?;
+    let public =
+        &point_r * &(&signature.s * &r_inverse) - &CurvePoint::generator_mul(&(hash * &r_inverse));
+
+    if public == CurvePoint::point_at_infinity() {
+        return None;
+    }
+
+    Some(public)
+}
+
+/// Converts the hash to an integer as in Section 4.1.3 of SEC 1 and reduces it modulo `n`.
+///
+/// If the hash has more bits than the group order, only its leftmost bits are used.
+pub(crate) fn hash_to_scalar<C: WeierstrassCurve>(message_hash: &[u8]) -> Scalar<C> {
+    Scalar::new(bits_to_int(message_hash, C::order().bits()))
+}
+
+#[cfg(test)]
+mod tests {
+    use k256::ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+    use num::BigInt;
+    use sha2::{Sha384, Sha512};
+
+    use crate::{
+        curves::{Bn128, PrimeOrderTestCurve, Secp256k1, TestCurve, P256, P384, P521},
+        test_utils::hex_int,
+    };
+
+    use super::*;
+
+    fn random_hash() -> [u8; 32] {
+        rand::random()
+    }
+
+    fn check_sign_and_verify<C: WeierstrassCurve + std::fmt::Debug>() {
+        let mut rng = rand::thread_rng();
+        let secret = Scalar::<C>::random(&mut rng);
+        let public = CurvePoint::generator_mul(&secret);
+        let hash = random_hash();
+
+        let signature = sign(&secret, &hash);
+        assert!(verify(&public, &hash, &signature));
+        assert!(verify(&public, &hash, &signature.normalize_s()));
+        assert!(signature.normalize_s().is_low_s());
+
+        let mut other_hash = hash;
+        other_hash[0] ^= 1;
+        assert!(!verify(&public, &other_hash, &signature));
+
+        let other_public = &public + &C::generator();
+        assert!(!verify(&other_public, &hash, &signature));
+        assert!(!verify(&CurvePoint::point_at_infinity(), &hash, &signature));
+
+        let swapped = Signature::new(signature.s().clone(), signature.r().clone()).unwrap();
+        assert!(!verify(&public, &hash, &swapped));
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        check_sign_and_verify::<Secp256k1>();
+        check_sign_and_verify::<Bn128>();
+        check_sign_and_verify::<P256>();
+        check_sign_and_verify::<P384>();
+        check_sign_and_verify::<P521>();
+    }
+
+    /// On a small curve, nonces that yield `r = 0` or `s = 0` actually occur.
+    #[test]
+    fn sign_and_verify_small_curve() {
+        let mut rng = rand::thread_rng();
+        for secret in 1..83 {
+            let secret = Scalar::<PrimeOrderTestCurve>::new(secret);
+            let public = PrimeOrderTestCurve::generator() * &secret;
+            let hash = [rand::random::<u8>()];
+
+            let signature = sign_with_rng(&secret, &hash, &mut rng);
+            assert!(verify(&public, &hash, &signature));
+        }
+    }
+
+    #[test]
+    fn signature_encoding() {
+        let signature = sign(&Scalar::<Secp256k1>::new(42), &random_hash());
+        let bytes = signature.to_bytes();
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(Signature::from_bytes(&bytes), Some(signature));
+
+        assert_eq!(Signature::<Secp256k1>::from_bytes(&bytes[1..]), None);
+        assert_eq!(Signature::<Secp256k1>::from_bytes(&[0; 64]), None);
+        assert_eq!(Signature::<Secp256k1>::from_bytes(&[0xff; 64]), None);
+    }
+
+    #[test]
+    fn hash_is_truncated_to_order_bits() {
+        let hash = [0xff; 64];
+        let scalar = hash_to_scalar::<Secp256k1>(&hash);
+        // 2^256 - 1 is reduced modulo n after truncating the hash to its leftmost 256 bits.
+        assert_eq!(
+            scalar,
+            Scalar::new((BigInt::from(1) << 256) - 1 - Secp256k1::order())
+        );
+
+        let scalar = hash_to_scalar::<PrimeOrderTestCurve>(&[0b1010_1100]);
+        // The order 83 has 7 bits, so the lowest bit of the hash is dropped.
+        assert_eq!(scalar, Scalar::new(0b101_0110 % 83));
+    }
+
+    /// Test that signatures created by this library are accepted by k256.
+    #[test]
+    fn k256_verifies_signatures() {
+        let secret_bytes: [u8; 32] = rand::random();
+        let secret = Scalar::<Secp256k1>::from_bytes_be_reduced(&secret_bytes);
+        let public = CurvePoint::generator_mul(&secret);
+        let hash = random_hash();
+
+        // k256 only accepts signatures with low s.
+        let signature = sign(&secret, &hash).normalize_s();
+        let k256_signature = k256::ecdsa::Signature::from_slice(&signature.to_bytes()).unwrap();
+        let verifying_key =
+            k256::ecdsa::VerifyingKey::from_sec1_bytes(&public.to_sec1(true)).unwrap();
+
+        assert!(verifying_key.verify_prehash(&hash, &k256_signature).is_ok());
+    }
+
+    /// Test that signatures created by k256 are accepted by this library.
+    #[test]
+    fn verifies_k256_signatures() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let public =
+            CurvePoint::<Secp256k1>::from_sec1(&signing_key.verifying_key().to_sec1_bytes())
+                .unwrap();
+        let hash = random_hash();
+
+        let k256_signature: k256::ecdsa::Signature = signing_key.sign_prehash(&hash).unwrap();
+        let signature = Signature::from_bytes(&k256_signature.to_bytes()).unwrap();
+
+        assert!(verify(&public, &hash, &signature));
+        // The high-s variant of the signature is valid as well.
+        let high_s = Signature::new(signature.r().clone(), -signature.s()).unwrap();
+        assert!(!high_s.is_low_s());
+        assert!(verify(&public, &hash, &high_s));
+    }
+
+    /// Test that the deterministic signatures equal those of k256, which also derives its nonces
+    /// with RFC 6979 and HMAC-SHA-256.
+    #[test]
+    fn deterministic_signatures_match_k256() {
+        for _ in 0..5 {
+            let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+            let secret = Scalar::<Secp256k1>::from_bytes_be(&signing_key.to_bytes()).unwrap();
+            let hash = random_hash();
+
+            let k256_signature: k256::ecdsa::Signature = signing_key.sign_prehash(&hash).unwrap();
+            let signature = sign(&secret, &hash);
+
+            // k256 normalizes s.
+            assert_eq!(
+                signature.normalize_s().to_bytes(),
+                k256_signature.to_bytes().as_slice()
+            );
+        }
+    }
+
+    /// Checks the nonce `k` and the signature `(r, s)` of the `message` from RFC 6979, where all
+    /// numbers are hexadecimal.
+    fn check_rfc6979<C: WeierstrassCurve + std::fmt::Debug, D: Digest + BlockSizeUser>(
+        secret: &str,
+        message: &str,
+        k: &str,
+        r: &str,
+        s: &str,
+    ) {
+        let secret = Scalar::<C>::new(hex_int(secret));
+        let hash = D::digest(message);
+
+        let mut nonces = NonceGenerator::<D>::new(secret.value(), &hash, &C::order());
+        assert_eq!(nonces.next(), Some(hex_int(k)), "{message}");
+
+        let signature = sign_deterministic::<C, D>(&secret, &hash);
+        assert_eq!(signature.r().value(), &hex_int(r), "{message}");
+        assert_eq!(signature.s().value(), &hex_int(s), "{message}");
+        assert!(verify(
+            &CurvePoint::generator_mul(&secret),
+            &hash,
+            &signature
+        ));
+    }
+
+    /// Test vectors from Appendix A.2.5 to A.2.7 of RFC 6979 for P-256, P-384 and P-521 with
+    /// SHA-256 and SHA-384.
+    #[test]
+    fn rfc6979_test_vectors() {
+        // RFC 6979, A.2.5.
+        let secret = "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721";
+        check_rfc6979::<P256, Sha256>(
+            secret,
+            "sample",
+            "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716",
+            "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+        );
+        check_rfc6979::<P256, Sha256>(
+            secret,
+            "test",
+            "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0",
+            "F1ABB023518351CD71D881567B1EA663ED3EFCF6C5132B354F28D3B0B7D38367",
+            "019F4113742A2B14BD25926B49C649155F267E60D3814B4C0CC84250E46F0083",
+        );
+        check_rfc6979::<P256, Sha384>(
+            secret,
+            "sample",
+            "09F634B188CEFD98E7EC88B1AA9852D734D0BC272F7D2A47DECC6EBEB375AAD4",
+            "0EAFEA039B20E9B42309FB1D89E213057CBF973DC0CFC8F129EDDDC800EF7719",
+            "4861F0491E6998B9455193E34E7B0D284DDD7149A74B95B9261F13ABDE940954",
+        );
+        check_rfc6979::<P256, Sha384>(
+            secret,
+            "test",
+            "16AEFFA357260B04B1DD199693960740066C1A8F3E8EDD79070AA914D361B3B8",
+            "83910E8B48BB0C74244EBDF7F07A1C5413D61472BD941EF3920E623FBCCEBEB6",
+            "8DDBEC54CF8CD5874883841D712142A56A8D0F218F5003CB0296B6B509619F2C",
+        );
+
+        // RFC 6979, A.2.6.
+        let secret = "6B9D3DAD2E1B8C1C05B19875B6659F4DE23C3B667BF297BA9AA47740787137D896D5724E4C70A825F872C9EA60D2EDF5";
+        check_rfc6979::<P384, Sha256>(
+            secret,
+            "sample",
+            "180AE9F9AEC5438A44BC159A1FCB277C7BE54FA20E7CF404B490650A8ACC414E375572342863C899F9F2EDF9747A9B60",
+            "21B13D1E013C7FA1392D03C5F99AF8B30C570C6F98D4EA8E354B63A21D3DAA33BDE1E888E63355D92FA2B3C36D8FB2CD",
+            "F3AA443FB107745BF4BD77CB3891674632068A10CA67E3D45DB2266FA7D1FEEBEFDC63ECCD1AC42EC0CB8668A4FA0AB0",
+        );
+        check_rfc6979::<P384, Sha256>(
+            secret,
+            "test",
+            "0CFAC37587532347DC3389FDC98286BBA8C73807285B184C83E62E26C401C0FAA48DD070BA79921A3457ABFF2D630AD7",
+            "6D6DEFAC9AB64DABAFE36C6BF510352A4CC27001263638E5B16D9BB51D451559F918EEDAF2293BE5B475CC8F0188636B",
+            "2D46F3BECBCC523D5F1A1256BF0C9B024D879BA9E838144C8BA6BAEB4B53B47D51AB373F9845C0514EEFB14024787265",
+        );
+        check_rfc6979::<P384, Sha384>(
+            secret,
+            "sample",
+            "94ED910D1A099DAD3254E9242AE85ABDE4BA15168EAF0CA87A555FD56D10FBCA2907E3E83BA95368623B8C4686915CF9",
+            "94EDBB92A5ECB8AAD4736E56C691916B3F88140666CE9FA73D64C4EA95AD133C81A648152E44ACF96E36DD1E80FABE46",
+            "99EF4AEB15F178CEA1FE40DB2603138F130E740A19624526203B6351D0A3A94FA329C145786E679E7B82C71A38628AC8",
+        );
+        check_rfc6979::<P384, Sha384>(
+            secret,
+            "test",
+            "015EE46A5BF88773ED9123A5AB0807962D193719503C527B031B4C2D225092ADA71F4A459BC0DA98ADB95837DB8312EA",
+            "8203B63D3C853E8D77227FB377BCF7B7B772E97892A80F36AB775D509D7A5FEB0542A7F0812998DA8F1DD3CA3CF023DB",
+            "DDD0760448D42D8A43AF45AF836FCE4DE8BE06B485E9B61B827C2F13173923E06A739F040649A667BF3B828246BAA5A5",
+        );
+
+        // RFC 6979, A.2.7.
+        let secret = concat!(
+            "0FAD06DAA62BA3B25D2FB40133DA757205DE67F5BB0018FEE8C86E1B68C7E75CA",
+            "A896EB32F1F47C70855836A6D16FCC1466F6D8FBEC67DB89EC0C08B0E996B83538",
+        );
+        check_rfc6979::<P521, Sha256>(
+            secret,
+            "sample",
+            concat!(
+                "0EDF38AFCAAECAB4383358B34D67C9F2216C8382AAEA44A3DAD5FDC9C32575761",
+                "793FEF24EB0FC276DFC4F6E3EC476752F043CF01415387470BCBD8678ED2C7E1A0",
+            ),
+            concat!(
+                "1511BB4D675114FE266FC4372B87682BAECC01D3CC62CF2303C92B3526012659D",
+                "16876E25C7C1E57648F23B73564D67F61C6F14D527D54972810421E7D87589E1A7",
+            ),
+            concat!(
+                "04A171143A83163D6DF460AAF61522695F207A58B95C0644D87E52AA1A347916E",
+                "4F7A72930B1BC06DBE22CE3F58264AFD23704CBB63B29B931F7DE6C9D949A7ECFC",
+            ),
+        );
+        check_rfc6979::<P521, Sha256>(
+            secret,
+            "test",
+            concat!(
+                "01DE74955EFAABC4C4F17F8E84D881D1310B5392D7700275F82F145C61E843841",
+                "AF09035BF7A6210F5A431A6A9E81C9323354A9E69135D44EBD2FCAA7731B909258",
+            ),
+            concat!(
+                "00E871C4A14F993C6C7369501900C4BC1E9C7B0B4BA44E04868B30B41D8071042",
+                "EB28C4C250411D0CE08CD197E4188EA4876F279F90B3D8D74A3C76E6F1E4656AA8",
+            ),
+            concat!(
+                "0CD52DBAA33B063C3A6CD8058A1FB0A46A4754B034FCC644766CA14DA8CA5CA9F",
+                "DE00E88C1AD60CCBA759025299079D7A427EC3CC5B619BFBC828E7769BCD694E86",
+            ),
+        );
+        check_rfc6979::<P521, Sha384>(
+            secret,
+            "sample",
+            concat!(
+                "1546A108BC23A15D6F21872F7DED661FA8431DDBD922D0DCDB77CC878C8553FFA",
+                "D064C95A920A750AC9137E527390D2D92F153E66196966EA554D9ADFCB109C4211",
+            ),
+            concat!(
+                "1EA842A0E17D2DE4F92C15315C63DDF72685C18195C2BB95E572B9C5136CA4B4B",
+                "576AD712A52BE9730627D16054BA40CC0B8D3FF035B12AE75168397F5D50C67451",
+            ),
+            concat!(
+                "1F21A3CEE066E1961025FB048BD5FE2B7924D0CD797BABE0A83B66F1E35EEAF5F",
+                "DE143FA85DC394A7DEE766523393784484BDF3E00114A1C857CDE1AA203DB65D61",
+            ),
+        );
+        check_rfc6979::<P521, Sha384>(
+            secret,
+            "test",
+            concat!(
+                "1F1FC4A349A7DA9A9E116BFDD055DC08E78252FF8E23AC276AC88B1770AE0B5DC",
+                "EB1ED14A4916B769A523CE1E90BA22846AF11DF8B300C38818F713DADD85DE0C88",
+            ),
+            concat!(
+                "14BEE21A18B6D8B3C93FAB08D43E739707953244FDBE924FA926D76669E7AC8C8",
+                "9DF62ED8975C2D8397A65A49DCC09F6B0AC62272741924D479354D74FF6075578C",
+            ),
+            concat!(
+                "133330865C067A0EAF72362A65E2D7BC4E461E8C8995C3B6226A21BD1AA78F0ED",
+                "94FE536A0DCA35534F0CD1510C41525D163FE9D74D134881E35141ED5E8E95B979",
+            ),
+        );
+    }
+
+    /// Test that the deterministic signatures equal those of p256 and p384, which derive their
+    /// nonces with HMAC-SHA-256 and HMAC-SHA-384, respectively.
+    #[test]
+    fn deterministic_signatures_match_nist_crates() {
+        let hash = random_hash();
+
+        let signing_key = p256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let secret = Scalar::<P256>::from_bytes_be(&signing_key.to_bytes()).unwrap();
+        let p256_signature: p256::ecdsa::Signature = signing_key.sign_prehash(&hash).unwrap();
+        assert_eq!(
+            sign(&secret, &hash).to_bytes(),
+            p256_signature.to_bytes().as_slice()
+        );
+
+        let signing_key = p384::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let secret = Scalar::<P384>::from_bytes_be(&signing_key.to_bytes()).unwrap();
+        let hash = Sha384::digest(hash);
+        let p384_signature: p384::ecdsa::Signature = signing_key.sign_prehash(&hash).unwrap();
+        let signature = sign_deterministic::<P384, Sha384>(&secret, &hash);
+        assert_eq!(signature.to_bytes(), p384_signature.to_bytes().as_slice());
+
+        let public =
+            CurvePoint::<P384>::from_sec1(&signing_key.verifying_key().to_sec1_bytes()).unwrap();
+        assert!(verify(&public, &hash, &signature));
+    }
+
+    #[test]
+    fn deterministic_signatures() {
+        let secret = Scalar::<Bn128>::new(123456789);
+        let public = CurvePoint::generator_mul(&secret);
+        let hash = random_hash();
+
+        assert_eq!(sign(&secret, &hash), sign(&secret, &hash));
+        assert_ne!(sign(&secret, &hash), sign(&secret, &[0; 32]));
+
+        let signature = sign_deterministic::<Bn128, Sha512>(&secret, &Sha512::digest(hash));
+        assert!(verify(&public, &Sha512::digest(hash), &signature));
+        assert_ne!(signature, sign(&secret, &Sha512::digest(hash)));
+
+        // On a small curve, the generator must skip nonces that result in invalid signatures.
+        for secret in 1..83 {
+            let secret = Scalar::<PrimeOrderTestCurve>::new(secret);
+            let public = PrimeOrderTestCurve::generator() * &secret;
+            for hash in 0..10 {
+                assert!(verify(&public, &[hash], &sign(&secret, &[hash])));
+            }
+        }
+    }
+
+    #[test]
+    fn public_key_recovery() {
+        let secret = Scalar::<Bn128>::random(&mut rand::thread_rng());
+        let public = CurvePoint::generator_mul(&secret);
+        let hash = random_hash();
+
+        let (signature, recovery_id) = sign_recoverable(&secret, &hash);
+        assert!(signature.is_low_s());
+        assert!(verify(&public, &hash, &signature));
+        assert_eq!(
+            recover_public_key(&hash, &signature, recovery_id),
+            Some(public.clone())
+        );
+        assert_ne!(
+            recover_public_key(&hash, &signature, recovery_id ^ 1),
+            Some(public)
+        );
+        assert_eq!(recover_public_key(&hash, &signature, 4), None);
+    }
+
+    /// The test curve's order 12 is not prime, so `r` and `s` may not be invertible, which must
+    /// be rejected instead of panicking.
+    #[test]
+    fn rejects_non_invertible_components() {
+        let signature = Signature::new(Scalar::<TestCurve>::new(5), Scalar::new(4)).unwrap();
+        assert!(!verify(&TestCurve::generator(), &[1], &signature));
+
+        let signature = Signature::new(Scalar::<TestCurve>::new(4), Scalar::new(1)).unwrap();
+        assert_eq!(recover_public_key(&[1], &signature, 0), None);
+    }
+}
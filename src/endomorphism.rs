@@ -0,0 +1,191 @@
+use num::{BigInt, Integer};
+
+use crate::{CurvePoint, FieldElement, Point, Scalar, WeierstrassCurve};
+
+/// An efficiently computable endomorphism `φ(x, y) = (βx, y)` on a curve with `a = 0`,
+/// which acts on the points of the prime order subgroup as a multiplication with `λ`,
+/// i.e. `φ(P) = λP`.
+///
+/// `β` is a primitive cube root of unity in the base field and `λ` is a primitive cube root
+/// of unity modulo the group order. Of the two possible choices for `λ`, it must be the one
+/// that matches `β`.
+///
+/// The endomorphism is used for the GLV method from Gallant, Lambert and Vanstone,
+/// "Faster Point Multiplication on Elliptic Curves with Efficient Endomorphisms":
+/// a scalar `k` is [decomposed](Endomorphism::decompose) into `k = k1 + k2 * λ` with `k1` and
+/// `k2` of roughly half the bit length of the group order, so that `kP = k1 * P + k2 * φ(P)`
+/// can be computed with half the number of doublings.
+#[derive(Debug)]
+pub struct Endomorphism<C: WeierstrassCurve> {
+    beta: FieldElement<C>,
+    lambda: Scalar<C>,
+    /// Two short vectors `(a, b)` with `a + b * λ = 0 mod n`, which span the lattice of all
+    /// such vectors.
+    basis: [(BigInt, BigInt); 2],
+}
+
+impl<C: WeierstrassCurve> Endomorphism<C> {
+    /// Creates the endomorphism for the given `beta` and `lambda`
+    /// and derives the lattice basis for the scalar decomposition.
+    pub fn new(beta: FieldElement<C>, lambda: Scalar<C>) -> Self {
+        let basis = lattice_basis(&C::order(), lambda.value());
+
+        Self {
+            beta,
+            lambda,
+            basis,
+        }
+    }
+
+    /// Returns the cube root of unity `β` in the base field.
+    pub fn beta(&self) -> &FieldElement<C> {
+        &self.beta
+    }
+
+    /// Returns the cube root of unity `λ` modulo the group order.
+    pub fn lambda(&self) -> &Scalar<C> {
+        &self.lambda
+    }
+
+    /// Applies the endomorphism to `point`, which is equivalent to multiplying it with `λ`.
+    pub fn apply(&self, point: &CurvePoint<C>) -> CurvePoint<C> {
+        match point.point() {
+            Point::PointAtInfinity => CurvePoint::point_at_infinity(),
+            Point::Point { x, y } => Point::Point {
+                x: &self.beta * x,
+                y: y.clone(),
+            }
+            .into(),
+        }
+    }
+
+    /// Decomposes `scalar` into `(k1, k2)` with `scalar = k1 + k2 * λ mod n`.
+    ///
+    /// Both halves may be negative and their absolute values have roughly half the bit length
+    /// of the group order `n`.
+    pub fn decompose(&self, scalar: &Scalar<C>) -> (BigInt, BigInt) {
+        let order = C::order();
+        let k = scalar.value();
+        let [(a1, b1), (a2, b2)] = &self.basis;
+
+        // Express (k, 0) in the basis with rational coefficients, which by Cramer's rule are
+        // k * b2 / n and -k * b1 / n, and round them to the nearest integers. The difference between (k, 0) and the resulting lattice vector
+        // is short and satisfies the decomposition since lattice vectors map to zero.
+        let c1 = rounded_division(&(b2 * k), &order);
+        let c2 = rounded_division(&(-b1 * k), &order);
+
+        let k1 = k - &c1 * a1 - &c2 * a2;
+        let k2 = -(c1 * b1) - c2 * b2;
+
+        (k1, k2)
+    }
+}
+
+/// Computes the lattice basis from Section 4 of the GLV paper by running the extended euclidean
+/// algorithm on `n` and `λ`, which yields a sequence of relations `s_i * n + t_i * λ = r_i`,
+/// i.e. vectors `(r_i, -t_i)` in the lattice with decreasing `r_i` and increasing `t_i`.
+fn lattice_basis(order: &BigInt, lambda: &BigInt) -> [(BigInt, BigInt); 2] {
+    let sqrt_order = order.sqrt();
+
+    // The remainders r_i and coefficients t_i of the current and the previous step.
+    let (mut remainder_prev, mut remainder) = (order.clone(), lambda.clone());
+    let (mut t_prev, mut t) = (BigInt::ZERO, BigInt::from(1));
+
+    // Find the largest l with r_l >= sqrt(n).
+    while remainder >= sqrt_order {
+        let quotient = &remainder_prev / &remainder;
+
+        let next_remainder = &remainder_prev - &quotient * &remainder;
+        remainder_prev = std::mem::replace(&mut remainder, next_remainder);
+
+        let next_t = &t_prev - quotient * &t;
+        t_prev = std::mem::replace(&mut t, next_t);
+    }
+
+    // Now (remainder_prev, t_prev) is step l and (remainder, t) is step l + 1.
+    let quotient = &remainder_prev / &remainder;
+    let remainder_next = &remainder_prev - &quotient * &remainder;
+    let t_next = &t_prev - quotient * &t;
+
+    let v1 = (remainder, -t);
+    let candidate_l = (remainder_prev, -t_prev);
+    let candidate_l2 = (remainder_next, -t_next);
+    let norm = |(a, b): &(BigInt, BigInt)| a * a + b * b;
+    let mut v2 = if norm(&candidate_l) <= norm(&candidate_l2) {
+        candidate_l
+    } else {
+        candidate_l2
+    };
+
+    // The determinant of the basis is ±n. Fix its sign to n, which the decomposition relies on.
+    if &v1.0 * &v2.1 - &v2.0 * &v1.1 < BigInt::ZERO {
+        v2 = (-v2.0, -v2.1);
+    }
+
+    [v1, v2]
+}
+
+/// Divides `numerator` by the positive `denominator`, rounding to the nearest integer.
+fn rounded_division(numerator: &BigInt, denominator: &BigInt) -> BigInt {
+    let numerator: BigInt = numerator * 2 + denominator;
+    numerator.div_floor(&(denominator * 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Signed;
+
+    use crate::curves::{Bn128, Secp256k1};
+
+    use super::*;
+
+    fn check_endomorphism<C: WeierstrassCurve + std::fmt::Debug>() {
+        let endomorphism = C::endomorphism().unwrap();
+
+        // β and λ must be cube roots of unity that match each other.
+        assert_eq!(
+            endomorphism.beta().pow(&BigInt::from(3)),
+            FieldElement::one()
+        );
+        assert_eq!(endomorphism.lambda().pow(&BigInt::from(3)), Scalar::one());
+        assert_eq!(
+            endomorphism.apply(&C::generator()),
+            C::generator() * endomorphism.lambda().value()
+        );
+
+        for (a, b) in &endomorphism.basis {
+            assert_eq!(
+                Scalar::<C>::new(a + b * endomorphism.lambda().value()),
+                Scalar::zero()
+            );
+        }
+
+        let half_bits = C::order().bits() / 2 + 2;
+        let mut rng = rand::thread_rng();
+        let scalars = (0..20).map(|_| Scalar::random(&mut rng)).chain([
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::<C>::one(),
+        ]);
+        for scalar in scalars {
+            let (k1, k2) = endomorphism.decompose(&scalar);
+
+            assert_eq!(
+                Scalar::new(&k1 + &k2 * endomorphism.lambda().value()),
+                scalar
+            );
+            assert!(k1.abs().bits() <= half_bits);
+            assert!(k2.abs().bits() <= half_bits);
+        }
+    }
+
+    #[test]
+    fn endomorphism_secp256k1() {
+        check_endomorphism::<Secp256k1>();
+    }
+
+    #[test]
+    fn endomorphism_bn128() {
+        check_endomorphism::<Bn128>();
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+/// Errors that can occur when constructing a [`CurvePoint`](crate::CurvePoint).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointError {
+    /// A coordinate is negative or not smaller than the field modulus.
+    CoordinateOutOfRange,
+    /// The coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// The encoded point has an unknown prefix or the wrong length for its prefix.
+    InvalidEncoding,
+    /// The point is on the curve, but not in the subgroup of the expected order.
+    NotInSubgroup,
+}
+
+impl Display for PointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PointError::CoordinateOutOfRange => {
+                write!(f, "coordinate is out of range of the field modulus")
+            }
+            PointError::NotOnCurve => write!(f, "point is not on the curve"),
+            PointError::InvalidEncoding => write!(f, "invalid point encoding"),
+            PointError::NotInSubgroup => write!(f, "point is not in the subgroup"),
+        }
+    }
+}
+
+impl std::error::Error for PointError {}
+
+/// Errors that can occur when executing one of the EVM [`precompiles`](crate::precompiles).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrecompileError {
+    /// An input point is invalid.
+    InvalidPoint(PointError),
+    /// The input length is invalid, e.g. not a multiple of 192 bytes for `ecPairing`.
+    InvalidInputLength,
+}
+
+impl From<PointError> for PrecompileError {
+    fn from(error: PointError) -> Self {
+        PrecompileError::InvalidPoint(error)
+    }
+}
+
+impl Display for PrecompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrecompileError::InvalidPoint(error) => write!(f, "invalid input point: {error}"),
+            PrecompileError::InvalidInputLength => write!(f, "invalid input length"),
+        }
+    }
+}
+
+impl std::error::Error for PrecompileError {}
+
+/// Errors that can occur when constructing a [`DynamicCurve`](crate::DynamicCurve).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurveError {
+    /// The field modulus is not a prime larger than three or has more than 576 bits.
+    InvalidFieldModulus,
+    /// The curve is singular, i.e. `4a^3 + 27b^2 = 0`.
+    SingularCurve,
+    /// The generator is not a point on the curve.
+    InvalidGenerator(PointError),
+    /// The order is not positive or multiplying the generator with it does not result in the
+    /// point at infinity.
+    InvalidOrder,
+    /// The number of points implied by the order and the cofactor violates Hasse's bound.
+    InvalidCofactor,
+}
+
+impl Display for CurveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CurveError::InvalidFieldModulus => {
+                write!(
+                    f,
+                    "field modulus is not a prime larger than three with at most 576 bits"
+                )
+            }
+            CurveError::SingularCurve => write!(f, "curve is singular"),
+            CurveError::InvalidGenerator(error) => write!(f, "invalid generator: {error}"),
+            CurveError::InvalidOrder => write!(f, "order does not match the generator"),
+            CurveError::InvalidCofactor => {
+                write!(f, "cofactor does not match the number of points")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
@@ -0,0 +1,257 @@
+//! Ethereum-specific helpers on top of [`ecdsa`](crate::ecdsa) over [`Secp256k1`].
+
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    curves::Secp256k1,
+    ecdsa::{self, Signature},
+    CurvePoint, Scalar,
+};
+
+/// Computes the Keccak-256 hash of `data`, as used throughout Ethereum.
+///
+/// Note that this is the original Keccak submission, which differs from the standardized
+/// SHA3-256 in its padding.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Derives the 20-byte Ethereum address of the `public` key, which consists of the last 20 bytes
+/// of the Keccak-256 hash of the uncompressed coordinates `x || y`.
+///
+/// ## Panics
+///
+/// Panics if `public` is the point at infinity, which is not a valid public key.
+pub fn address(public: &CurvePoint<Secp256k1>) -> [u8; 20] {
+    assert!(
+        *public != CurvePoint::point_at_infinity(),
+        "the point at infinity has no address"
+    );
+
+    // Strip the 0x04 prefix of the uncompressed encoding.
+    let hash = keccak256(&public.to_sec1(false)[1..]);
+    // SAFETY: The slice has exactly 20 bytes.
+    hash[12..].try_into().unwrap()
+}
+
+/// Recovers the public key that signed `message_hash` from the Ethereum signature `(r, s, v)`.
+///
+/// `v` may be given in any of the forms used by Ethereum: `27` or `28` for legacy signatures
+/// and the `ecrecover` precompile, `35 + 2 * chain_id` or `36 + 2 * chain_id` for
+/// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) transactions, or the plain y-parity `0`
+/// or `1` of typed transactions.
+///
+/// Returns `None` if `v` is invalid, `r` or `s` is zero or not smaller than the group order,
+/// or `s` is greater than half the group order. The latter follows the transaction validity rule
+/// of [EIP-2](https://eips.ethereum.org/EIPS/eip-2), unlike the `ecrecover` precompile,
+/// which accepts high `s` values.
+pub fn recover_public_key(
+    message_hash: &[u8; 32],
+    r: &[u8; 32],
+    s: &[u8; 32],
+    v: u64,
+) -> Option<CurvePoint<Secp256k1>> {
+    let recovery_id = match v {
+        0 | 1 => v,
+        27 | 28 => v - 27,
+        35.. => (v - 35) % 2,
+        _ => return None,
+    };
+
+    let signature = Signature::new(Scalar::from_bytes_be(r)?, Scalar::from_bytes_be(s)?)?;
+    ecdsa::recover_public_key(message_hash, &signature, recovery_id as u8)
+}
+
+/// Recovers the address of the signer of `message_hash` from the Ethereum signature `(r, s, v)`,
+/// see [`recover_public_key`].
+pub fn recover_address(
+    message_hash: &[u8; 32],
+    r: &[u8; 32],
+    s: &[u8; 32],
+    v: u64,
+) -> Option<[u8; 20]> {
+    recover_public_key(message_hash, r, s, v).map(|public| address(&public))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils, WeierstrassCurve};
+
+    use super::*;
+
+    fn hex<const N: usize>(value: &str) -> [u8; N] {
+        test_utils::hex(value).try_into().unwrap()
+    }
+
+    #[test]
+    fn keccak256_of_empty_input() {
+        assert_eq!(
+            keccak256(&[]),
+            hex("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+    }
+
+    /// The private key 1 belongs to the well-known address 0x7E5F...BdF, i.e. `address(G)`.
+    #[test]
+    fn address_of_generator() {
+        assert_eq!(
+            address(&CurvePoint::generator_mul(&Scalar::one())),
+            hex("7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+        );
+    }
+
+    /// Signed Ethereum mainnet transactions given by their transaction hash, raw signed RLP
+    /// payload, the payload that was signed, i.e. the fields without the signature and with the
+    /// chain id for EIP-155, and `(r, s, v)` as found in the signed payload.
+    #[test]
+    fn recovers_mainnet_transaction_signers() {
+        let transactions = [
+            // Legacy EIP-155 transaction with chain id 1, i.e. v = 37.
+            (
+                "280cde7cdefe4b188750e76c888f13bd05ce9a4d7767730feefe8a0e50ca6fc4",
+                concat!(
+                    "f9015482078b8505d21dba0083022ef1947a250d5630b4cf539739df2c5dacb4c659f2488d880c46",
+                    "549a521b13d8b8e47ff36ab50000000000000000000000000000000000000000000066ab5a608bd0",
+                    "0a23f2fe000000000000000000000000000000000000000000000000000000000000008000000000",
+                    "000000000000000048c04ed5691981c42154c6167398f95e8f38a7ff000000000000000000000000",
+                    "00000000000000000000000000000000632ceac70000000000000000000000000000000000000000",
+                    "000000000000000000000002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead908",
+                    "3c756cc20000000000000000000000006c6ee5e31d828de241282b9606c8e98ea48526e225a0c907",
+                    "7369501641a92ef7399ff81c21639ed4fd8fc69cb793cfa1dbfab342e10aa0615facb2f1bcf3274a",
+                    "354cfe384a38d0cc008a11c2dd23a69111bc6930ba27a8",
+                ),
+                concat!(
+                    "f9011482078b8505d21dba0083022ef1947a250d5630b4cf539739df2c5dacb4c659f2488d880c46",
+                    "549a521b13d8b8e47ff36ab50000000000000000000000000000000000000000000066ab5a608bd0",
+                    "0a23f2fe000000000000000000000000000000000000000000000000000000000000008000000000",
+                    "000000000000000048c04ed5691981c42154c6167398f95e8f38a7ff000000000000000000000000",
+                    "00000000000000000000000000000000632ceac70000000000000000000000000000000000000000",
+                    "000000000000000000000002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead908",
+                    "3c756cc20000000000000000000000006c6ee5e31d828de241282b9606c8e98ea48526e2018080",
+                ),
+                "c9077369501641a92ef7399ff81c21639ed4fd8fc69cb793cfa1dbfab342e10a",
+                "615facb2f1bcf3274a354cfe384a38d0cc008a11c2dd23a69111bc6930ba27a8",
+                37,
+                "a12e1462d0ced572f396f58b6e2d03894cd7c8a4",
+            ),
+            // EIP-1559 transaction with y-parity 0, whose payloads are prefixed by the type 0x02.
+            (
+                "ce4dc6d7a7549a98ee3b071b67e970879ff51b5b95d1c340bacd80fa1e1aab31",
+                concat!(
+                    "02f86f0102843b9aca0085029e7822d68298f094d9e1459a7a482635700cbc20bbaf52d495ab9c96",
+                    "80841b55ba3ac080a0c199674fcb29f353693dd779c017823b954b3c69dffa3cd6b2a6ff78887980",
+                    "39a028ca912de909e7e6cdef9cdcaf24c54dd8c1032946dfa1d85c206b32a9064fe8",
+                ),
+                concat!(
+                    "02ec0102843b9aca0085029e7822d68298f094d9e1459a7a482635700cbc20bbaf52d495ab9c9680",
+                    "841b55ba3ac0",
+                ),
+                "c199674fcb29f353693dd779c017823b954b3c69dffa3cd6b2a6ff7888798039",
+                "28ca912de909e7e6cdef9cdcaf24c54dd8c1032946dfa1d85c206b32a9064fe8",
+                0,
+                "001e2b7de757ba469a57bf6b23d982458a07efce",
+            ),
+        ];
+
+        for (transaction_hash, signed, unsigned, r, s, v, signer) in transactions {
+            let signed = test_utils::hex(signed);
+            assert_eq!(keccak256(&signed), hex(transaction_hash));
+
+            // r and s are the last two fields of the signed payload.
+            let signature = [&[0xa0][..], &hex::<32>(r), &[0xa0], &hex::<32>(s)].concat();
+            assert!(signed.ends_with(&signature));
+
+            let hash = keccak256(&test_utils::hex(unsigned));
+            let (r, s) = (hex(r), hex(s));
+            assert_eq!(recover_address(&hash, &r, &s, v), Some(hex(signer)));
+
+            // The other parity recovers a different key.
+            assert_ne!(recover_address(&hash, &r, &s, v ^ 1), Some(hex(signer)));
+        }
+    }
+
+    /// The example transaction of [EIP-155](https://eips.ethereum.org/EIPS/eip-155), signed with
+    /// the private key `0x4646...46`.
+    #[test]
+    fn recovers_eip155_example_signer() {
+        let unsigned = test_utils::hex(concat!(
+            "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000",
+            "80018080",
+        ));
+        let hash = keccak256(&unsigned);
+        assert_eq!(
+            hash,
+            hex("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+        );
+
+        let r = hex("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276");
+        let s = hex("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+        let secret = Scalar::<Secp256k1>::from_bytes_be(&[0x46; 32]).unwrap();
+        assert_eq!(
+            recover_address(&hash, &r, &s, 37),
+            Some(address(&CurvePoint::generator_mul(&secret)))
+        );
+    }
+
+    #[test]
+    fn recovers_signers_of_own_signatures() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            let secret = Scalar::<Secp256k1>::random(&mut rng);
+            let public = CurvePoint::generator_mul(&secret);
+            let hash = keccak256(&rand::random::<[u8; 32]>());
+
+            let (signature, recovery_id) = ecdsa::sign_recoverable(&secret, &hash);
+            let r = signature.r().to_bytes_be().try_into().unwrap();
+            let s = signature.s().to_bytes_be().try_into().unwrap();
+
+            let v = u64::from(recovery_id);
+            for v in [v, 27 + v, 35 + 2 * 5 + v] {
+                assert_eq!(recover_public_key(&hash, &r, &s, v), Some(public.clone()));
+            }
+            assert_eq!(recover_address(&hash, &r, &s, v), Some(address(&public)));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_signatures() {
+        let hash = [0x42; 32];
+        let (signature, recovery_id) = ecdsa::sign_recoverable(&Scalar::<Secp256k1>::new(7), &hash);
+        let r: [u8; 32] = signature.r().to_bytes_be().try_into().unwrap();
+        let s: [u8; 32] = signature.s().to_bytes_be().try_into().unwrap();
+        let v = 27 + u64::from(recovery_id);
+        assert!(recover_public_key(&hash, &r, &s, v).is_some());
+
+        // High s.
+        let high_s = (-signature.s()).to_bytes_be().try_into().unwrap();
+        assert_eq!(recover_public_key(&hash, &r, &high_s, v), None);
+
+        // Zero and out-of-range values.
+        let order: [u8; 32] = Secp256k1::order().to_bytes_be().1.try_into().unwrap();
+        assert_eq!(recover_public_key(&hash, &[0; 32], &s, v), None);
+        assert_eq!(recover_public_key(&hash, &r, &[0; 32], v), None);
+        assert_eq!(recover_public_key(&hash, &order, &s, v), None);
+        assert_eq!(recover_public_key(&hash, &r, &order, v), None);
+
+        // Invalid v.
+        for v in [2, 26, 29, 34] {
+            assert_eq!(recover_public_key(&hash, &r, &s, v), None);
+        }
+    }
+
+    /// Test against k256's recoverable signatures.
+    #[test]
+    fn recovers_k256_signatures() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let hash = keccak256(b"hello");
+        let (k256_signature, recovery_id) = signing_key.sign_prehash_recoverable(&hash).unwrap();
+
+        let signature = Signature::<Secp256k1>::from_bytes(&k256_signature.to_bytes()).unwrap();
+        let public = ecdsa::recover_public_key(&hash, &signature, recovery_id.to_byte()).unwrap();
+
+        assert_eq!(
+            public.to_sec1(true),
+            signing_key.verifying_key().to_sec1_bytes().as_ref()
+        );
+    }
+}
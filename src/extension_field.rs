@@ -0,0 +1,788 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use num::BigInt;
+
+use crate::{FieldElement, PairingCurve, TwistType, WeierstrassCurve};
+
+/// An element `c0 + c1 * u` of the quadratic extension `Fp2 = Fp[u] / (u^2 + 1)` of the prime
+/// field over which the curve `C` is defined.
+///
+/// This is only a field if `-1` is not a square in `Fp`, i.e. if the field modulus is `3 mod 4`,
+/// which holds for the commonly used pairing-friendly curves.
+pub struct Fp2<C: WeierstrassCurve> {
+    c0: FieldElement<C>,
+    c1: FieldElement<C>,
+}
+
+impl<C: WeierstrassCurve> Debug for Fp2<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Fp2")
+            .field(&self.c0.value())
+            .field(&self.c1.value())
+            .finish()
+    }
+}
+
+impl<C: WeierstrassCurve> Clone for Fp2<C> {
+    fn clone(&self) -> Self {
+        Self {
+            c0: self.c0.clone(),
+            c1: self.c1.clone(),
+        }
+    }
+}
+
+impl<C: WeierstrassCurve> PartialEq for Fp2<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1
+    }
+}
+
+impl<C: WeierstrassCurve> Eq for Fp2<C> {}
+
+impl<C: WeierstrassCurve> From<FieldElement<C>> for Fp2<C> {
+    fn from(c0: FieldElement<C>) -> Self {
+        Self::new(c0, FieldElement::zero())
+    }
+}
+
+impl<C: WeierstrassCurve> Fp2<C> {
+    /// Creates the element `c0 + c1 * u`.
+    pub fn new(c0: FieldElement<C>, c1: FieldElement<C>) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Returns the additive identity.
+    pub fn zero() -> Self {
+        Self::new(FieldElement::zero(), FieldElement::zero())
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one() -> Self {
+        Self::new(FieldElement::one(), FieldElement::zero())
+    }
+
+    /// Returns the coefficient `c0`.
+    pub fn c0(&self) -> &FieldElement<C> {
+        &self.c0
+    }
+
+    /// Returns the coefficient `c1` of `u`.
+    pub fn c1(&self) -> &FieldElement<C> {
+        &self.c1
+    }
+
+    /// Returns `true` if the element is zero.
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    /// Returns the square of the element.
+    pub fn square(&self) -> Self {
+        // (c0 + c1 u)^2 = (c0 + c1)(c0 - c1) + 2 c0 c1 u
+        let c0 = (&self.c0 + &self.c1) * &(&self.c0 - &self.c1);
+        let c1 = &self.c0 * &self.c1;
+        Self::new(c0, &c1 + &c1)
+    }
+
+    /// Returns the conjugate `c0 - c1 * u`, which is also the image of the element under the
+    /// Frobenius map `x -> x^p`.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0.clone(), -&self.c1)
+    }
+
+    /// Multiplies both coefficients with the element `factor` of the base field.
+    pub fn scale(&self, factor: &FieldElement<C>) -> Self {
+        Self::new(&self.c0 * factor, &self.c1 * factor)
+    }
+
+    /// Raises the element to the power of `exponent`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `exponent` is negative.
+    pub fn pow(&self, exponent: &BigInt) -> Self {
+        assert!(exponent >= &BigInt::ZERO, "exponent must not be negative");
+
+        let mut result = Self::one();
+        for bit in (0..exponent.bits()).rev() {
+            result = result.square();
+            if exponent.bit(bit) {
+                result = result * self;
+            }
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of the element or `None` if it is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        // 1 / (c0 + c1 u) = (c0 - c1 u) / (c0^2 + c1^2)
+        let norm_inverse = (self.c0.square() + &self.c1.square()).inverse()?;
+        Some(self.conjugate().scale(&norm_inverse))
+    }
+
+    /// Returns a square root `r` of the element such that `r * r = self`,
+    /// or `None` if the element is not a square.
+    ///
+    /// The other square root is `-r`. This uses Algorithm 9 from Adj and Rodríguez-Henríquez,
+    /// "Square root computation over even extension fields", which relies on the field modulus
+    /// being `3 mod 4`.
+    pub fn sqrt(&self) -> Option<Self> {
+        let p = C::field_modulus();
+        let minus_one = -Self::one();
+
+        let a1 = self.pow(&((&p - 3) / 4));
+        let alpha = a1.square() * self;
+        // alpha^p * alpha is the norm of alpha, which is -1 if and only if self is not a square.
+        if alpha.conjugate() * &alpha == minus_one {
+            return None;
+        }
+
+        let x0 = a1 * self;
+        if alpha == minus_one {
+            // Multiply with u.
+            return Some(Self::new(-&x0.c1, x0.c0));
+        }
+
+        let b = (alpha + &Self::one()).pow(&((p - 1) / 2));
+        Some(b * &x0)
+    }
+}
+
+impl<C: WeierstrassCurve> Add<&Fp2<C>> for &Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn add(self, rhs: &Fp2<C>) -> Self::Output {
+        Fp2::new(&self.c0 + &rhs.c0, &self.c1 + &rhs.c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Add<&Fp2<C>> for Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn add(self, rhs: &Fp2<C>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Sub<&Fp2<C>> for &Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn sub(self, rhs: &Fp2<C>) -> Self::Output {
+        Fp2::new(&self.c0 - &rhs.c0, &self.c1 - &rhs.c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Sub<&Fp2<C>> for Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn sub(self, rhs: &Fp2<C>) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Mul<&Fp2<C>> for &Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn mul(self, rhs: &Fp2<C>) -> Self::Output {
+        // Karatsuba multiplication with u^2 = -1.
+        let v0 = &self.c0 * &rhs.c0;
+        let v1 = &self.c1 * &rhs.c1;
+        let c1 = (&self.c0 + &self.c1) * &(&rhs.c0 + &rhs.c1) - &v0 - &v1;
+        Fp2::new(v0 - &v1, c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Mul<&Fp2<C>> for Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn mul(self, rhs: &Fp2<C>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Neg for &Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn neg(self) -> Self::Output {
+        Fp2::new(-&self.c0, -&self.c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Neg for Fp2<C> {
+    type Output = Fp2<C>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+/// An element `c0 + c1 * v + c2 * v^2` of the cubic extension `Fp6 = Fp2[v] / (v^3 - ξ)`,
+/// where `ξ` is the curve's [`non_residue`](PairingCurve::non_residue).
+pub struct Fp6<C: PairingCurve> {
+    c0: Fp2<C>,
+    c1: Fp2<C>,
+    c2: Fp2<C>,
+}
+
+impl<C: PairingCurve> Debug for Fp6<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Fp6")
+            .field(&self.c0)
+            .field(&self.c1)
+            .field(&self.c2)
+            .finish()
+    }
+}
+
+impl<C: PairingCurve> Clone for Fp6<C> {
+    fn clone(&self) -> Self {
+        Self {
+            c0: self.c0.clone(),
+            c1: self.c1.clone(),
+            c2: self.c2.clone(),
+        }
+    }
+}
+
+impl<C: PairingCurve> PartialEq for Fp6<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1 && self.c2 == other.c2
+    }
+}
+
+impl<C: PairingCurve> Eq for Fp6<C> {}
+
+impl<C: PairingCurve> From<Fp2<C>> for Fp6<C> {
+    fn from(c0: Fp2<C>) -> Self {
+        Self::new(c0, Fp2::zero(), Fp2::zero())
+    }
+}
+
+impl<C: PairingCurve> Fp6<C> {
+    /// Creates the element `c0 + c1 * v + c2 * v^2`.
+    pub fn new(c0: Fp2<C>, c1: Fp2<C>, c2: Fp2<C>) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Returns the additive identity.
+    pub fn zero() -> Self {
+        Self::new(Fp2::zero(), Fp2::zero(), Fp2::zero())
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one() -> Self {
+        Self::new(Fp2::one(), Fp2::zero(), Fp2::zero())
+    }
+
+    /// Returns the coefficient `c0`.
+    pub fn c0(&self) -> &Fp2<C> {
+        &self.c0
+    }
+
+    /// Returns the coefficient `c1` of `v`.
+    pub fn c1(&self) -> &Fp2<C> {
+        &self.c1
+    }
+
+    /// Returns the coefficient `c2` of `v^2`.
+    pub fn c2(&self) -> &Fp2<C> {
+        &self.c2
+    }
+
+    /// Returns `true` if the element is zero.
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    /// Returns the square of the element.
+    pub fn square(&self) -> Self {
+        self * self
+    }
+
+    /// Multiplies the element with `v`, which shifts the coefficients since `v^3 = ξ`.
+    pub fn mul_by_v(&self) -> Self {
+        Self::new(
+            &self.c2 * &C::non_residue(),
+            self.c0.clone(),
+            self.c1.clone(),
+        )
+    }
+
+    /// Returns the multiplicative inverse of the element or `None` if it is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let non_residue = C::non_residue();
+
+        let t0 = self.c0.square() - &(&self.c1 * &self.c2 * &non_residue);
+        let t1 = &self.c2.square() * &non_residue - &(&self.c0 * &self.c1);
+        let t2 = self.c1.square() - &(&self.c0 * &self.c2);
+
+        let norm = &self.c0 * &t0 + &((&self.c2 * &t1 + &(&self.c1 * &t2)) * &non_residue);
+        let norm_inverse = norm.inverse()?;
+
+        Some(Self::new(
+            t0 * &norm_inverse,
+            t1 * &norm_inverse,
+            t2 * &norm_inverse,
+        ))
+    }
+}
+
+impl<C: PairingCurve> Add<&Fp6<C>> for &Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn add(self, rhs: &Fp6<C>) -> Self::Output {
+        Fp6::new(&self.c0 + &rhs.c0, &self.c1 + &rhs.c1, &self.c2 + &rhs.c2)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Add<&Fp6<C>> for Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn add(self, rhs: &Fp6<C>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl<C: PairingCurve> Sub<&Fp6<C>> for &Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn sub(self, rhs: &Fp6<C>) -> Self::Output {
+        Fp6::new(&self.c0 - &rhs.c0, &self.c1 - &rhs.c1, &self.c2 - &rhs.c2)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Sub<&Fp6<C>> for Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn sub(self, rhs: &Fp6<C>) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl<C: PairingCurve> Mul<&Fp6<C>> for &Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn mul(self, rhs: &Fp6<C>) -> Self::Output {
+        // Karatsuba multiplication with v^3 = ξ, see Section 4 of
+        // "Multiplication and Squaring on Pairing-Friendly Fields" by Devegili et al.
+        let non_residue = C::non_residue();
+        let v0 = &self.c0 * &rhs.c0;
+        let v1 = &self.c1 * &rhs.c1;
+        let v2 = &self.c2 * &rhs.c2;
+
+        let c0 = ((&self.c1 + &self.c2) * &(&rhs.c1 + &rhs.c2) - &v1 - &v2) * &non_residue + &v0;
+        let c1 = (&self.c0 + &self.c1) * &(&rhs.c0 + &rhs.c1) - &v0 - &v1 + &(&v2 * &non_residue);
+        let c2 = (&self.c0 + &self.c2) * &(&rhs.c0 + &rhs.c2) - &v0 - &v2 + &v1;
+
+        Fp6::new(c0, c1, c2)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Mul<&Fp6<C>> for Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn mul(self, rhs: &Fp6<C>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<C: PairingCurve> Neg for &Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn neg(self) -> Self::Output {
+        Fp6::new(-&self.c0, -&self.c1, -&self.c2)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Neg for Fp6<C> {
+    type Output = Fp6<C>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+/// An element `c0 + c1 * w` of the quadratic extension `Fp12 = Fp6[w] / (w^2 - v)`.
+///
+/// Pairings map into the subgroup of `r`-th roots of unity of this field, where `r` is the
+/// [`order`](WeierstrassCurve::order) of the curve.
+pub struct Fp12<C: PairingCurve> {
+    c0: Fp6<C>,
+    c1: Fp6<C>,
+}
+
+impl<C: PairingCurve> Debug for Fp12<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Fp12")
+            .field(&self.c0)
+            .field(&self.c1)
+            .finish()
+    }
+}
+
+impl<C: PairingCurve> Clone for Fp12<C> {
+    fn clone(&self) -> Self {
+        Self {
+            c0: self.c0.clone(),
+            c1: self.c1.clone(),
+        }
+    }
+}
+
+impl<C: PairingCurve> PartialEq for Fp12<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1
+    }
+}
+
+impl<C: PairingCurve> Eq for Fp12<C> {}
+
+impl<C: PairingCurve> Fp12<C> {
+    /// Creates the element `c0 + c1 * w`.
+    pub fn new(c0: Fp6<C>, c1: Fp6<C>) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Returns the additive identity.
+    pub fn zero() -> Self {
+        Self::new(Fp6::zero(), Fp6::zero())
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one() -> Self {
+        Self::new(Fp6::one(), Fp6::zero())
+    }
+
+    /// Returns the coefficient `c0`.
+    pub fn c0(&self) -> &Fp6<C> {
+        &self.c0
+    }
+
+    /// Returns the coefficient `c1` of `w`.
+    pub fn c1(&self) -> &Fp6<C> {
+        &self.c1
+    }
+
+    /// Returns `true` if the element is zero.
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    /// Returns the square of the element.
+    pub fn square(&self) -> Self {
+        // (c0 + c1 w)^2 = (c0 + c1)(c0 + v c1) - (1 + v) c0 c1 + 2 c0 c1 w
+        let product = &self.c0 * &self.c1;
+        let c0 = (&self.c0 + &self.c1) * &(&self.c0 + &self.c1.mul_by_v())
+            - &product
+            - &product.mul_by_v();
+        Self::new(c0, &product + &product)
+    }
+
+    /// Returns the conjugate `c0 - c1 * w`, which is the image of the element under the
+    /// Frobenius map `x -> x^(p^6)`.
+    ///
+    /// For elements of norm one, such as pairing results, this is also the inverse.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0.clone(), -&self.c1)
+    }
+
+    /// Returns the image `x^p` of the element under the Frobenius map.
+    ///
+    /// Writing the element as `sum(b_k * w^k)` with `b_k` in `Fp2`, this computes
+    /// `sum(conj(b_k) * ξ^(k(p - 1) / 6) * w^k)`, which is much cheaper than exponentiating.
+    pub fn frobenius_map(&self) -> Self {
+        let coefficients = C::frobenius_coefficients();
+        let map = |value: &Fp2<C>, k: usize| value.conjugate() * coefficients.power(k);
+
+        Self::new(
+            Fp6::new(
+                self.c0.c0.conjugate(),
+                map(&self.c0.c1, 2),
+                map(&self.c0.c2, 4),
+            ),
+            Fp6::new(
+                map(&self.c1.c0, 1),
+                map(&self.c1.c1, 3),
+                map(&self.c1.c2, 5),
+            ),
+        )
+    }
+
+    /// Raises the element to the power of `exponent`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `exponent` is negative.
+    pub fn pow(&self, exponent: &BigInt) -> Self {
+        assert!(exponent >= &BigInt::ZERO, "exponent must not be negative");
+
+        let mut result = Self::one();
+        for bit in (0..exponent.bits()).rev() {
+            result = result.square();
+            if exponent.bit(bit) {
+                result = result * self;
+            }
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of the element or `None` if it is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        // 1 / (c0 + c1 w) = (c0 - c1 w) / (c0^2 - v c1^2)
+        let norm_inverse = (self.c0.square() - &self.c1.square().mul_by_v()).inverse()?;
+        Some(Self::new(
+            &self.c0 * &norm_inverse,
+            -(&self.c1 * &norm_inverse),
+        ))
+    }
+}
+
+/// The precomputed coefficients `ξ^(k(p - 1) / 6)` for `k = 0, ..., 5`, which are needed for the
+/// Frobenius map on [`Fp12`] and on points of the twist.
+///
+/// These are well-defined since `p = 1 mod 6` for the supported curves. Curves store them in a
+/// lazily initialized static next to their `G2` generator and return them from
+/// [`PairingCurve::frobenius_coefficients`].
+#[derive(Debug)]
+pub struct FrobeniusCoefficients<C: PairingCurve> {
+    powers: [Fp2<C>; 6],
+    /// The coefficients of `x` and `y` for the endomorphism `ψ` of the twist.
+    twist: (Fp2<C>, Fp2<C>),
+}
+
+impl<C: PairingCurve> FrobeniusCoefficients<C> {
+    /// Computes the coefficients from the [non-residue](PairingCurve::non_residue) of `C`.
+    pub fn new() -> Self {
+        let gamma = C::non_residue().pow(&((C::field_modulus() - 1) / 6));
+
+        let mut powers = [
+            Fp2::one(),
+            Fp2::one(),
+            Fp2::one(),
+            Fp2::one(),
+            Fp2::one(),
+            Fp2::one(),
+        ];
+        for k in 1..6 {
+            powers[k] = &powers[k - 1] * &gamma;
+        }
+
+        let twist = match C::twist_type() {
+            TwistType::D => (powers[2].clone(), powers[3].clone()),
+            // SAFETY: The coefficients are powers of the non-residue, which is not zero.
+            TwistType::M => (powers[2].inverse().unwrap(), powers[3].inverse().unwrap()),
+        };
+
+        Self { powers, twist }
+    }
+
+    /// Returns `ξ^(k(p - 1) / 6)`.
+    pub(crate) fn power(&self, k: usize) -> &Fp2<C> {
+        &self.powers[k]
+    }
+
+    /// Returns the coefficients by which `ψ` multiplies the conjugated coordinates of a point
+    /// on the twist.
+    pub(crate) fn twist(&self) -> (&Fp2<C>, &Fp2<C>) {
+        (&self.twist.0, &self.twist.1)
+    }
+}
+
+impl<C: PairingCurve> Default for FrobeniusCoefficients<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: PairingCurve> Add<&Fp12<C>> for &Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn add(self, rhs: &Fp12<C>) -> Self::Output {
+        Fp12::new(&self.c0 + &rhs.c0, &self.c1 + &rhs.c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Add<&Fp12<C>> for Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn add(self, rhs: &Fp12<C>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl<C: PairingCurve> Sub<&Fp12<C>> for &Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn sub(self, rhs: &Fp12<C>) -> Self::Output {
+        Fp12::new(&self.c0 - &rhs.c0, &self.c1 - &rhs.c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Sub<&Fp12<C>> for Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn sub(self, rhs: &Fp12<C>) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl<C: PairingCurve> Mul<&Fp12<C>> for &Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn mul(self, rhs: &Fp12<C>) -> Self::Output {
+        // Karatsuba multiplication with w^2 = v.
+        let v0 = &self.c0 * &rhs.c0;
+        let v1 = &self.c1 * &rhs.c1;
+        let c1 = (&self.c0 + &self.c1) * &(&rhs.c0 + &rhs.c1) - &v0 - &v1;
+        Fp12::new(v0 + &v1.mul_by_v(), c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Mul<&Fp12<C>> for Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn mul(self, rhs: &Fp12<C>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<C: PairingCurve> Neg for &Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn neg(self) -> Self::Output {
+        Fp12::new(-&self.c0, -&self.c1)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Neg for Fp12<C> {
+    type Output = Fp12<C>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::Sign;
+    use rand::RngCore;
+
+    use crate::curves::Bn128;
+
+    use super::*;
+
+    fn random_fp2() -> Fp2<Bn128> {
+        let random_element = || {
+            let mut bytes = [0; 32];
+            rand::thread_rng().fill_bytes(&mut bytes);
+            FieldElement::new(BigInt::from_bytes_be(Sign::Plus, &bytes))
+        };
+        Fp2::new(random_element(), random_element())
+    }
+
+    fn random_fp6() -> Fp6<Bn128> {
+        Fp6::new(random_fp2(), random_fp2(), random_fp2())
+    }
+
+    fn random_fp12() -> Fp12<Bn128> {
+        Fp12::new(random_fp6(), random_fp6())
+    }
+
+    #[test]
+    fn fp2_arithmetic() {
+        let u = Fp2::<Bn128>::new(FieldElement::zero(), FieldElement::one());
+        assert_eq!(u.square(), -Fp2::one());
+        assert_eq!(Fp2::<Bn128>::zero().inverse(), None);
+
+        for _ in 0..10 {
+            let (a, b, c) = (random_fp2(), random_fp2(), random_fp2());
+            assert_eq!(&a * &(&b + &c), &a * &b + &(&a * &c));
+            assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+            assert_eq!(a.square(), &a * &a);
+            assert_eq!(a.inverse().unwrap() * &a, Fp2::one());
+            assert_eq!(a.pow(&Bn128::field_modulus()), a.conjugate());
+            assert_eq!(&a - &a, Fp2::zero());
+        }
+    }
+
+    #[test]
+    fn fp2_square_root() {
+        assert_eq!(Fp2::<Bn128>::zero().sqrt(), Some(Fp2::zero()));
+        // -1 = u^2 is a square in Fp2, even though it is not a square in Fp.
+        let minus_one = -Fp2::<Bn128>::one();
+        assert_eq!(minus_one.sqrt().unwrap().square(), minus_one);
+        // Squares cover half of the non-zero elements and ξ is not one of them.
+        assert_eq!(Bn128::non_residue().sqrt(), None);
+
+        for _ in 0..10 {
+            let a = random_fp2();
+            let root = a.square().sqrt().unwrap();
+            assert!(root == a || root == -&a);
+            assert_eq!((&a.square() * &Bn128::non_residue()).sqrt(), None);
+        }
+    }
+
+    #[test]
+    fn fp6_arithmetic() {
+        let v = Fp6::<Bn128>::new(Fp2::zero(), Fp2::one(), Fp2::zero());
+        assert_eq!(&v * &v.square(), Fp6::from(Bn128::non_residue()));
+        assert_eq!(Fp6::<Bn128>::zero().inverse(), None);
+
+        for _ in 0..10 {
+            let (a, b, c) = (random_fp6(), random_fp6(), random_fp6());
+            assert_eq!(&a * &(&b + &c), &a * &b + &(&a * &c));
+            assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+            assert_eq!(a.mul_by_v(), &a * &v);
+            assert_eq!(a.inverse().unwrap() * &a, Fp6::one());
+        }
+    }
+
+    #[test]
+    fn fp12_arithmetic() {
+        let w = Fp12::<Bn128>::new(Fp6::zero(), Fp6::one());
+        let v = Fp6::new(Fp2::zero(), Fp2::one(), Fp2::zero());
+        assert_eq!(w.square(), Fp12::new(v, Fp6::zero()));
+        assert_eq!(Fp12::<Bn128>::zero().inverse(), None);
+
+        for _ in 0..5 {
+            let (a, b, c) = (random_fp12(), random_fp12(), random_fp12());
+            assert_eq!(&a * &(&b + &c), &a * &b + &(&a * &c));
+            assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+            assert_eq!(a.square(), &a * &a);
+            assert_eq!(a.inverse().unwrap() * &a, Fp12::one());
+        }
+    }
+
+    #[test]
+    fn frobenius_map() {
+        let a = random_fp12();
+        let p = Bn128::field_modulus();
+        assert_eq!(a.frobenius_map(), a.pow(&p));
+
+        let mut image = a.clone();
+        for _ in 0..6 {
+            image = image.frobenius_map();
+        }
+        assert_eq!(image, a.conjugate());
+        for _ in 0..6 {
+            image = image.frobenius_map();
+        }
+        assert_eq!(image, a);
+    }
+}
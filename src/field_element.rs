@@ -0,0 +1,458 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use num::{bigint::Sign, traits::Euclid, BigInt};
+
+use crate::{
+    montgomery::{Limbs, MAX_LIMBS},
+    MontgomeryParameters, PointError, WeierstrassCurve,
+};
+
+/// An element of the prime field over which the curve `C` is defined.
+///
+/// The element is stored in Montgomery form with the curve's
+/// [`montgomery_parameters`](WeierstrassCurve::montgomery_parameters), so that additions and
+/// multiplications do not need divisions by the field modulus. It is only converted back to its
+/// value in the range `[0, field_modulus)` when the value is requested, e.g. for serialization.
+pub struct FieldElement<C: WeierstrassCurve> {
+    limbs: Limbs,
+    phantom: PhantomData<C>,
+}
+
+impl<C: WeierstrassCurve> Debug for FieldElement<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FieldElement").field(&self.value()).finish()
+    }
+}
+
+impl<C: WeierstrassCurve> PartialEq for FieldElement<C> {
+    fn eq(&self, other: &Self) -> bool {
+        // The Montgomery form is unique, since it is fully reduced.
+        self.limbs == other.limbs
+    }
+}
+
+impl<C: WeierstrassCurve> Eq for FieldElement<C> {}
+
+impl<C: WeierstrassCurve> Clone for FieldElement<C> {
+    fn clone(&self) -> Self {
+        Self::from_limbs(self.limbs)
+    }
+}
+
+impl<C: WeierstrassCurve> FieldElement<C> {
+    /// Creates a new field element from `value`, reducing it modulo the field modulus.
+    pub fn new(value: impl Into<BigInt>) -> Self {
+        let value = Euclid::rem_euclid(&value.into(), &C::field_modulus());
+        Self::from_limbs(C::montgomery_parameters().encode(&value))
+    }
+
+    /// Creates the element from limbs in Montgomery form.
+    fn from_limbs(limbs: Limbs) -> Self {
+        Self {
+            limbs,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the additive identity.
+    pub fn zero() -> Self {
+        Self::from_limbs([0; MAX_LIMBS])
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one() -> Self {
+        Self::from_limbs(C::montgomery_parameters().one())
+    }
+
+    /// Returns the reduced value of the element, converting it out of Montgomery form.
+    pub fn value(&self) -> BigInt {
+        PrimeFieldElement::value(self)
+    }
+
+    /// Returns the big-endian byte representation of the element.
+    ///
+    /// The result is left-padded with zeros to the byte length of the field modulus.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        PrimeFieldElement::to_bytes_be(self)
+    }
+
+    /// Creates an element from its big-endian byte representation.
+    ///
+    /// Returns an error if the value is not smaller than the field modulus.
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<Self, PointError> {
+        let value = BigInt::from_bytes_be(Sign::Plus, bytes);
+        if value >= C::field_modulus() {
+            return Err(PointError::CoordinateOutOfRange);
+        }
+
+        Ok(Self::new(value))
+    }
+
+    /// Returns the number of bytes needed to represent any field element.
+    pub fn byte_len() -> usize {
+        C::field_modulus().bits().div_ceil(8) as usize
+    }
+
+    /// Returns `true` if the element is zero.
+    pub fn is_zero(&self) -> bool {
+        PrimeFieldElement::is_zero(self)
+    }
+
+    /// Returns the square of the element.
+    pub fn square(&self) -> Self {
+        PrimeFieldElement::square(self)
+    }
+
+    /// Raises the element to the power of `exponent`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `exponent` is negative.
+    pub fn pow(&self, exponent: &BigInt) -> Self {
+        PrimeFieldElement::pow(self, exponent)
+    }
+
+    /// Returns the multiplicative inverse of the element or `None` if it is zero.
+    ///
+    /// The inverse is computed as `self^(p - 2)` by Fermat's little theorem, so it stays in
+    /// Montgomery form.
+    pub fn inverse(&self) -> Option<Self> {
+        PrimeFieldElement::inverse(self)
+    }
+
+    /// Returns a square root `r` of the element such that `r * r = self`,
+    /// or `None` if the element is not a quadratic residue.
+    ///
+    /// The other square root is `-r`. The root is the same as the one returned by [`mod_sqrt`],
+    /// but it is computed in Montgomery form: with `r = self^((p + 1) / 4)` if `p = 3 mod 4`
+    /// and with the Tonelli-Shanks algorithm otherwise.
+    ///
+    /// [`mod_sqrt`]: crate::mod_sqrt
+    pub fn sqrt(&self) -> Option<Self> {
+        PrimeFieldElement::sqrt(self)
+    }
+}
+
+/// An element of a prime field in Montgomery form, which knows the [`MontgomeryParameters`] of
+/// its field.
+///
+/// [`FieldElement`] looks the parameters up through its curve type, while the elements of a
+/// [`DynamicCurve`](crate::DynamicCurve) carry a reference to them. The point arithmetic is
+/// generic over this trait, so that both share the same implementation.
+pub(crate) trait PrimeFieldElement:
+    Clone
+    + PartialEq
+    + Debug
+    + Neg<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+{
+    /// Returns the parameters of the field.
+    fn parameters(&self) -> &MontgomeryParameters;
+
+    /// Returns the limbs of the element in Montgomery form.
+    fn limbs(&self) -> &Limbs;
+
+    /// Creates an element of the same field from limbs in Montgomery form.
+    fn with_limbs(&self, limbs: Limbs) -> Self;
+
+    /// Returns the reduced value of the element, converting it out of Montgomery form.
+    fn value(&self) -> BigInt {
+        self.parameters().decode(self.limbs())
+    }
+
+    /// Returns the big-endian byte representation of the element, left-padded with zeros to the
+    /// byte length of the field modulus.
+    fn to_bytes_be(&self) -> Vec<u8> {
+        let byte_len = self.parameters().modulus().bits().div_ceil(8) as usize;
+        let (_, bytes) = self.value().to_bytes_be();
+        let mut padded = vec![0; byte_len - bytes.len()];
+        padded.extend(bytes);
+        padded
+    }
+
+    /// Returns `true` if the element is zero.
+    fn is_zero(&self) -> bool {
+        self.limbs() == &[0; MAX_LIMBS]
+    }
+
+    /// Returns the square of the element.
+    fn square(&self) -> Self {
+        self.clone() * self
+    }
+
+    /// Raises the element to the power of `exponent`, see [`FieldElement::pow`].
+    fn pow(&self, exponent: &BigInt) -> Self {
+        assert!(
+            exponent.sign() != Sign::Minus,
+            "exponent must not be negative"
+        );
+
+        // Left-to-right square-and-multiply, staying in Montgomery form.
+        let mut result = self.with_limbs(self.parameters().one());
+        for bit in (0..exponent.bits()).rev() {
+            result = result.square();
+            if exponent.bit(bit) {
+                result = result * self;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the multiplicative inverse of the element, see [`FieldElement::inverse`].
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Some(self.pow(&(self.parameters().modulus() - 2)))
+    }
+
+    /// Returns a square root of the element, see [`FieldElement::sqrt`].
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self.clone());
+        }
+
+        let p = self.parameters().modulus();
+        if p.bit(1) {
+            let root = self.pow(&((&p + 1) >> 2));
+            return (root.square() == *self).then_some(root);
+        }
+
+        // Euler's criterion.
+        let one = self.with_limbs(self.parameters().one());
+        if self.pow(&((&p - 1) >> 1)) != one {
+            return None;
+        }
+
+        // Write p - 1 = q * 2^s with q odd.
+        let p_minus_one: BigInt = &p - 1;
+        let s = p_minus_one.trailing_zeros().unwrap();
+        let q = &p_minus_one >> s;
+
+        // Find a quadratic non-residue z.
+        let mut z = one.clone() + &one;
+        while z.pow(&(&p_minus_one >> 1)) == one {
+            z = z + &one;
+        }
+
+        let mut m = s;
+        let mut c = z.pow(&q);
+        let mut t = self.pow(&q);
+        let mut r = self.pow(&((&q + 1) >> 1));
+
+        while t != one {
+            // Find the least i such that t^(2^i) = 1.
+            let mut i = 0;
+            let mut t_squared = t.clone();
+            while t_squared != one {
+                t_squared = t_squared.square();
+                i += 1;
+            }
+
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.square();
+            }
+            m = i;
+            c = b.square();
+            t = t * &c;
+            r = r * &b;
+        }
+
+        Some(r)
+    }
+}
+
+/// The arithmetic operators on references to a [`PrimeFieldElement`] `E`.
+///
+/// Bounds on references are not implied by a trait, so generic code that uses these operators
+/// requires `for<'a> &'a E: FieldRef<E>`.
+pub(crate) trait FieldRef<E>:
+    Sized + Add<Self, Output = E> + Sub<Self, Output = E> + Mul<Self, Output = E> + Neg<Output = E>
+{
+}
+
+impl<T, E> FieldRef<E> for T where
+    T: Add<T, Output = E> + Sub<T, Output = E> + Mul<T, Output = E> + Neg<Output = E>
+{
+}
+
+impl<C: WeierstrassCurve> PrimeFieldElement for FieldElement<C> {
+    fn parameters(&self) -> &MontgomeryParameters {
+        C::montgomery_parameters()
+    }
+
+    fn limbs(&self) -> &Limbs {
+        &self.limbs
+    }
+
+    fn with_limbs(&self, limbs: Limbs) -> Self {
+        Self::from_limbs(limbs)
+    }
+}
+
+impl<C: WeierstrassCurve> Add<&FieldElement<C>> for &FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn add(self, rhs: &FieldElement<C>) -> Self::Output {
+        FieldElement::from_limbs(C::montgomery_parameters().add(&self.limbs, &rhs.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Add<&FieldElement<C>> for FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn add(self, rhs: &FieldElement<C>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Sub<&FieldElement<C>> for &FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn sub(self, rhs: &FieldElement<C>) -> Self::Output {
+        FieldElement::from_limbs(C::montgomery_parameters().sub(&self.limbs, &rhs.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Sub<&FieldElement<C>> for FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn sub(self, rhs: &FieldElement<C>) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Mul<&FieldElement<C>> for &FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn mul(self, rhs: &FieldElement<C>) -> Self::Output {
+        FieldElement::from_limbs(C::montgomery_parameters().mul(&self.limbs, &rhs.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Mul<&FieldElement<C>> for FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn mul(self, rhs: &FieldElement<C>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Neg for &FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn neg(self) -> Self::Output {
+        FieldElement::from_limbs(C::montgomery_parameters().neg(&self.limbs))
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Neg for FieldElement<C> {
+    type Output = FieldElement<C>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::{Bn128, Mod17TestCurve, Secp256k1, TestCurve};
+
+    use super::*;
+
+    #[test]
+    fn values_are_reduced() {
+        assert_eq!(FieldElement::<TestCurve>::new(12).value(), BigInt::from(1));
+        assert_eq!(FieldElement::<TestCurve>::new(-1).value(), BigInt::from(10));
+        assert_eq!(
+            FieldElement::<TestCurve>::new(7) + &FieldElement::new(5),
+            FieldElement::new(1)
+        );
+        assert_eq!(
+            FieldElement::<TestCurve>::new(3) - &FieldElement::new(5),
+            FieldElement::new(9)
+        );
+        assert_eq!(
+            FieldElement::<TestCurve>::new(3) * &FieldElement::new(5),
+            FieldElement::new(4)
+        );
+        assert_eq!(-FieldElement::<TestCurve>::new(3), FieldElement::new(8));
+        assert_eq!(-FieldElement::<TestCurve>::zero(), FieldElement::zero());
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(FieldElement::<TestCurve>::zero().inverse(), None);
+
+        for value in 1..11 {
+            let element = FieldElement::<TestCurve>::new(value);
+            assert_eq!(element.inverse().unwrap() * &element, FieldElement::one());
+        }
+
+        let element = FieldElement::<Bn128>::new(123456789);
+        assert_eq!(element.inverse().unwrap() * &element, FieldElement::one());
+    }
+
+    #[test]
+    fn byte_conversion() {
+        let element = FieldElement::<Secp256k1>::new(0x1234);
+        let bytes = element.to_bytes_be();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(&bytes[30..], &[0x12, 0x34]);
+        assert_eq!(FieldElement::from_bytes_be(&bytes), Ok(element));
+
+        let modulus_bytes = Secp256k1::field_modulus().to_bytes_be().1;
+        assert_eq!(
+            FieldElement::<Secp256k1>::from_bytes_be(&modulus_bytes),
+            Err(PointError::CoordinateOutOfRange)
+        );
+    }
+
+    fn check_square_roots<C: WeierstrassCurve + std::fmt::Debug + PartialEq>(
+        values: impl IntoIterator<Item = i64>,
+    ) {
+        for value in values {
+            let element = FieldElement::<C>::new(value);
+            let square = element.square();
+            let root = square.sqrt().unwrap();
+            assert!(root == element || root == -&element);
+        }
+    }
+
+    #[test]
+    fn sqrt() {
+        check_square_roots::<TestCurve>(0..11);
+        check_square_roots::<Mod17TestCurve>(0..17);
+        for value in 0..17 {
+            assert_eq!(
+                FieldElement::<Mod17TestCurve>::new(value)
+                    .sqrt()
+                    .map(|root| root.value()),
+                crate::mod_sqrt(value.into(), 17.into())
+            );
+        }
+        check_square_roots::<Bn128>([2, 3, 1 << 40, -5]);
+        check_square_roots::<Secp256k1>([2, 3, 1 << 40, -5]);
+
+        // The quadratic non-residues mod 11 and mod 17.
+        for value in [2, 6, 7, 8, 10] {
+            assert_eq!(FieldElement::<TestCurve>::new(value).sqrt(), None);
+        }
+        for value in [3, 5, 6, 7, 10, 11, 12, 14] {
+            assert_eq!(FieldElement::<Mod17TestCurve>::new(value).sqrt(), None);
+        }
+    }
+}
@@ -0,0 +1,510 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::BigInt;
+
+use crate::{FieldElement, Fp2, PairingCurve, PointError, Scalar, TwistType};
+
+/// A point on the sextic twist `E': y^2 = x^3 + b'` of the curve `C` over [`Fp2`],
+/// where `b'` is `b / ξ` or `b * ξ` depending on the curve's [`TwistType`] and `ξ` is the
+/// curve's [`non_residue`](PairingCurve::non_residue).
+///
+/// The twist contains a subgroup of the same order as the curve's [`generator`], which is
+/// the second input group `G2` of the pairing. The twist usually has a large cofactor though,
+/// so points from untrusted sources must be checked with [`G2Point::is_in_subgroup`].
+///
+/// [`generator`]: crate::WeierstrassCurve::generator
+#[derive(Debug)]
+pub struct G2Point<C: PairingCurve> {
+    /// The coordinates `(x, y)` or `None` for the point at infinity.
+    coordinates: Option<(Fp2<C>, Fp2<C>)>,
+}
+
+impl<C: PairingCurve> Clone for G2Point<C> {
+    fn clone(&self) -> Self {
+        Self {
+            coordinates: self.coordinates.clone(),
+        }
+    }
+}
+
+impl<C: PairingCurve> PartialEq for G2Point<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coordinates == other.coordinates
+    }
+}
+
+impl<C: PairingCurve> Eq for G2Point<C> {}
+
+impl<C: PairingCurve> G2Point<C> {
+    /// Creates a new point on the twist with the given coordinates.
+    ///
+    /// Returns an error if the point does not satisfy the curve equation of the twist.
+    /// This does not check whether the point is in the subgroup `G2`.
+    pub fn try_new(x: Fp2<C>, y: Fp2<C>) -> Result<Self, PointError> {
+        let point = Self::new_unchecked(x, y);
+        if !point.is_on_curve() {
+            return Err(PointError::NotOnCurve);
+        }
+
+        Ok(point)
+    }
+
+    /// Creates a new point on the twist with the given coordinates without validating them.
+    ///
+    /// Only use this for trusted inputs, such as constants. Otherwise use [`G2Point::try_new`].
+    pub fn new_unchecked(x: Fp2<C>, y: Fp2<C>) -> Self {
+        Self {
+            coordinates: Some((x, y)),
+        }
+    }
+
+    /// Creates the `G2Point` representing the point at infinity, i.e. the identity element.
+    pub fn point_at_infinity() -> Self {
+        Self { coordinates: None }
+    }
+
+    /// Returns the generator of `G2`.
+    pub fn generator() -> Self {
+        C::g2_generator()
+    }
+
+    /// Returns the point's x, y coordinates in that order or `None` if it's the point at infinity.
+    pub fn as_coordinates(&self) -> Option<(&Fp2<C>, &Fp2<C>)> {
+        self.coordinates.as_ref().map(|(x, y)| (x, y))
+    }
+
+    /// Returns the parameter `b'` of the twist's curve equation, which is `b / ξ` for a
+    /// D-type twist and `b * ξ` for an M-type twist.
+    pub fn b() -> Fp2<C> {
+        let b = Fp2::from(FieldElement::new(C::b()));
+        match C::twist_type() {
+            // SAFETY: The non-residue is not zero.
+            TwistType::D => b * &C::non_residue().inverse().unwrap(),
+            TwistType::M => b * &C::non_residue(),
+        }
+    }
+
+    /// Returns `true` if the point satisfies the twist's curve equation `y^2 = x^3 + b'`.
+    ///
+    /// The point at infinity is always on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        let Some((x, y)) = &self.coordinates else {
+            return true;
+        };
+
+        y.square() == x.square() * x + &Self::b()
+    }
+
+    /// Returns `true` if the point is on the twist and in the subgroup `G2`, i.e. if multiplying
+    /// it with the group order results in the point at infinity.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.is_on_curve() && (self * &C::order()).coordinates.is_none()
+    }
+
+    /// Returns the image of the point under the endomorphism `ψ` of the twist that corresponds
+    /// to the Frobenius map `(x, y) -> (x^p, y^p)` on the curve over `Fp12`.
+    pub fn frobenius_map(&self) -> Self {
+        let Some((x, y)) = &self.coordinates else {
+            return Self::point_at_infinity();
+        };
+
+        let (x_coefficient, y_coefficient) = C::frobenius_coefficients().twist();
+        Self::new_unchecked(x.conjugate() * x_coefficient, y.conjugate() * y_coefficient)
+    }
+
+    /// Doubles the point.
+    pub fn double(&self) -> Self {
+        let Some((x, y)) = &self.coordinates else {
+            return Self::point_at_infinity();
+        };
+
+        // The tangent at a point with y = 0 is vertical.
+        let Some(two_y_inverse) = (y + y).inverse() else {
+            return Self::point_at_infinity();
+        };
+        let x_squared = x.square();
+        let lambda = (&x_squared + &x_squared + &x_squared) * &two_y_inverse;
+
+        let x_r = lambda.square() - x - x;
+        let y_r = lambda * &(x - &x_r) - y;
+        Self::new_unchecked(x_r, y_r)
+    }
+
+    /// Multiplies `self` with the secret `scalar` using a Montgomery ladder.
+    ///
+    /// This is the counterpart of [`CurvePoint::mul_ct`](crate::CurvePoint::mul_ct) on the
+    /// twist: the ladder always iterates over as many bits as the group order has, adds with
+    /// complete formulas and swaps its intermediate points arithmetically, so the sequence of
+    /// field operations does not depend on the scalar. Use this method for secret scalars,
+    /// e.g. when signing.
+    ///
+    /// The complete formulas require a twist without points of order two, which holds for the
+    /// supported curves since their twists have odd order.
+    pub fn mul_ct(&self, scalar: &Scalar<C>) -> G2Point<C> {
+        let mut r0 = TwistProjectivePoint::point_at_infinity();
+        let mut r1 = TwistProjectivePoint::from(self);
+
+        for bit in (0..C::order().bits()).rev() {
+            let choice = scalar.value().bit(bit);
+
+            // Invariant: r1 = r0 + self.
+            r0.conditional_swap(&mut r1, choice);
+            r1 = r0.add(&r1);
+            r0 = r0.add(&r0);
+            r0.conditional_swap(&mut r1, choice);
+        }
+
+        r0.to_affine()
+    }
+
+    /// Adds `q` to `self` on the twist.
+    fn add(&self, q: &G2Point<C>) -> G2Point<C> {
+        let (Some((x_p, y_p)), Some((x_q, y_q))) = (&self.coordinates, &q.coordinates) else {
+            return match self.coordinates {
+                None => q.clone(),
+                Some(_) => self.clone(),
+            };
+        };
+
+        if x_p == x_q {
+            return if y_p == y_q {
+                self.double()
+            } else {
+                Self::point_at_infinity()
+            };
+        }
+
+        // SAFETY: The inverse exists since x_p != x_q.
+        let lambda = (y_q - y_p) * &(x_q - x_p).inverse().unwrap();
+        let x_r = lambda.square() - x_p - x_q;
+        let y_r = lambda * &(x_p - &x_r) - y_p;
+        Self::new_unchecked(x_r, y_r)
+    }
+
+    /// Returns the inverse `-P` of the point.
+    pub fn negate(&self) -> Self {
+        Self {
+            coordinates: self.coordinates.as_ref().map(|(x, y)| (x.clone(), -y)),
+        }
+    }
+
+    /// Multiplies the point with `scalar` using double-and-add.
+    ///
+    /// Negative scalars are handled by multiplying the negated point with the absolute value.
+    fn multiply(&self, scalar: &BigInt) -> Self {
+        if scalar < &BigInt::ZERO {
+            return self.negate().multiply(&-scalar);
+        }
+
+        let mut result = Self::point_at_infinity();
+        for bit in (0..scalar.bits()).rev() {
+            result = result.double();
+            if scalar.bit(bit) {
+                result = result.add(self);
+            }
+        }
+        result
+    }
+}
+
+/// A point on the twist in homogeneous projective coordinates `(X : Y : Z)`, which represents
+/// the affine point `(X / Z, Y / Z)`, for [`G2Point::mul_ct`].
+///
+/// Like [`ProjectivePoint`](crate::projective::ProjectivePoint) on the curve itself, points are
+/// added with the complete formulas for `a = 0` from Renes, Costello and Batina,
+/// "Complete addition formulas for prime order elliptic curves"
+/// (https://eprint.iacr.org/2015/1060), Algorithm 7.
+struct TwistProjectivePoint<C: PairingCurve> {
+    x: Fp2<C>,
+    y: Fp2<C>,
+    z: Fp2<C>,
+}
+
+impl<C: PairingCurve> From<&G2Point<C>> for TwistProjectivePoint<C> {
+    fn from(point: &G2Point<C>) -> Self {
+        match &point.coordinates {
+            None => Self::point_at_infinity(),
+            Some((x, y)) => Self {
+                x: x.clone(),
+                y: y.clone(),
+                z: Fp2::one(),
+            },
+        }
+    }
+}
+
+impl<C: PairingCurve> TwistProjectivePoint<C> {
+    /// Returns the point at infinity, represented as `(0 : 1 : 0)`.
+    fn point_at_infinity() -> Self {
+        Self {
+            x: Fp2::zero(),
+            y: Fp2::one(),
+            z: Fp2::zero(),
+        }
+    }
+
+    /// Converts the point back to affine coordinates using a single inversion.
+    fn to_affine(&self) -> G2Point<C> {
+        let Some(z_inverse) = self.z.inverse() else {
+            return G2Point::point_at_infinity();
+        };
+
+        G2Point::new_unchecked(&self.x * &z_inverse, &self.y * &z_inverse)
+    }
+
+    /// Swaps `self` and `other` if `choice` is `true` without branching on `choice`, computing
+    /// `t = c * (a - b), a = a - t, b = b + t` for `c` either zero or one.
+    fn conditional_swap(&mut self, other: &mut Self, choice: bool) {
+        let choice = Fp2::from(FieldElement::<C>::new(u8::from(choice)));
+
+        for (a, b) in [
+            (&mut self.x, &mut other.x),
+            (&mut self.y, &mut other.y),
+            (&mut self.z, &mut other.z),
+        ] {
+            let t = &choice * &(&*a - b);
+            *a = &*a - &t;
+            *b = &*b + &t;
+        }
+    }
+
+    /// Adds `other` to `self` using the complete formulas.
+    fn add(&self, other: &Self) -> Self {
+        let b = G2Point::<C>::b();
+        let b3 = &b + &b + &b;
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * &(x2 + y2);
+        let t4 = &t0 + &t1;
+        let t3 = t3 - &t4;
+        let t4 = (y1 + z1) * &(y2 + z2);
+        let x3 = &t1 + &t2;
+        let t4 = t4 - &x3;
+        let x3 = (x1 + z1) * &(x2 + z2);
+        let y3 = &t0 + &t2;
+        let y3 = x3 - &y3;
+        let x3 = &t0 + &t0;
+        let t0 = x3 + &t0;
+        let t2 = &b3 * &t2;
+        let z3 = &t1 + &t2;
+        let t1 = t1 - &t2;
+        let y3 = &b3 * &y3;
+        let x3 = &t4 * &y3;
+        let t2 = &t3 * &t1;
+        let x3 = t2 - &x3;
+        let y3 = y3 * &t0;
+        let t1 = t1 * &z3;
+        let y3 = t1 + &y3;
+        let t0 = t0 * &t3;
+        let z3 = z3 * &t4;
+        let z3 = z3 + &t0;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<C: PairingCurve> Add<&G2Point<C>> for &G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn add(self, q: &G2Point<C>) -> Self::Output {
+        G2Point::add(self, q)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Add<&G2Point<C>> for G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn add(self, q: &G2Point<C>) -> Self::Output {
+        G2Point::add(&self, q)
+    }
+}
+
+impl<C: PairingCurve> Sub<&G2Point<C>> for &G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn sub(self, q: &G2Point<C>) -> Self::Output {
+        G2Point::add(self, &q.negate())
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Sub<&G2Point<C>> for G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn sub(self, q: &G2Point<C>) -> Self::Output {
+        G2Point::add(&self, &q.negate())
+    }
+}
+
+impl<C: PairingCurve> Neg for &G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn neg(self) -> Self::Output {
+        G2Point::negate(self)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Neg for G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn neg(self) -> Self::Output {
+        G2Point::negate(&self)
+    }
+}
+
+impl<C: PairingCurve> Mul<&Scalar<C>> for &G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn mul(self, scalar: &Scalar<C>) -> Self::Output {
+        G2Point::multiply(self, scalar.value())
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Mul<&Scalar<C>> for G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn mul(self, scalar: &Scalar<C>) -> Self::Output {
+        &self * scalar
+    }
+}
+
+/// Multiplies the point with an arbitrary integer, which is not reduced modulo the group order.
+///
+/// This is useful for points outside of `G2`, e.g. for clearing the cofactor.
+/// Prefer multiplying with a [`Scalar`] otherwise.
+impl<C: PairingCurve> Mul<&BigInt> for &G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn mul(self, scalar: &BigInt) -> Self::Output {
+        G2Point::multiply(self, scalar)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: PairingCurve> Mul<&BigInt> for G2Point<C> {
+    type Output = G2Point<C>;
+
+    fn mul(self, scalar: &BigInt) -> Self::Output {
+        G2Point::multiply(&self, scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::{Bls12_381, Bn128};
+
+    use super::*;
+
+    #[test]
+    fn generator_is_in_subgroup() {
+        fn check<C: PairingCurve + std::fmt::Debug>() {
+            let generator = G2Point::<C>::generator();
+            assert!(generator.is_on_curve());
+            assert!(generator.is_in_subgroup());
+            assert_eq!(&generator * &(C::order() - 1), generator.negate());
+        }
+
+        check::<Bn128>();
+        check::<Bls12_381>();
+    }
+
+    #[test]
+    fn group_law() {
+        let generator = G2Point::<Bn128>::generator();
+        let a = Scalar::new(123456789);
+        let b = Scalar::new(987654321);
+
+        assert_eq!(
+            &generator * &a + &(&generator * &b),
+            &generator * &(&a + &b)
+        );
+        assert_eq!(
+            &generator * &a - &(&generator * &a),
+            G2Point::point_at_infinity()
+        );
+        assert_eq!(generator.double(), &generator + &generator);
+        assert_eq!(G2Point::point_at_infinity() + &generator, generator);
+    }
+
+    /// The twist of bn128 has a large cofactor, so most points on it are not in `G2`.
+    #[test]
+    fn points_outside_of_subgroup() {
+        let point = G2Point::<Bn128>::try_new(
+            Fp2::one(),
+            Fp2::new(
+                FieldElement::new(
+                    BigInt::parse_bytes(
+                        b"18278151005453108793778860132295291098363647455926340152056652516292830556603",
+                        10,
+                    )
+                    .unwrap(),
+                ),
+                FieldElement::new(
+                    BigInt::parse_bytes(
+                        b"5912654199736721486680175016176231956195085055698687135131307249486702594212",
+                        10,
+                    )
+                    .unwrap(),
+                ),
+            ),
+        )
+        .unwrap();
+        assert!(point.is_on_curve());
+        assert!(!point.is_in_subgroup());
+
+        let not_on_curve = G2Point::<Bn128>::new_unchecked(Fp2::one(), Fp2::one());
+        assert!(!not_on_curve.is_on_curve());
+        assert!(!not_on_curve.is_in_subgroup());
+        assert_eq!(
+            G2Point::<Bn128>::try_new(Fp2::one(), Fp2::one()),
+            Err(PointError::NotOnCurve)
+        );
+    }
+
+    /// `ψ` acts on `G2` as multiplication with `p`.
+    #[test]
+    fn frobenius_map() {
+        fn check<C: PairingCurve + std::fmt::Debug>() {
+            let generator = G2Point::<C>::generator();
+            assert_eq!(generator.frobenius_map(), &generator * &C::field_modulus());
+        }
+
+        check::<Bn128>();
+        check::<Bls12_381>();
+    }
+
+    #[test]
+    fn constant_time_multiplication() {
+        fn check<C: PairingCurve + std::fmt::Debug>() {
+            let generator = G2Point::<C>::generator();
+            let point = &generator * &Scalar::new(42);
+
+            let mut rng = rand::thread_rng();
+            let scalars = [Scalar::zero(), Scalar::one(), -Scalar::one()]
+                .into_iter()
+                .chain((0..3).map(|_| Scalar::random(&mut rng)));
+            for scalar in scalars {
+                assert_eq!(generator.mul_ct(&scalar), &generator * &scalar);
+                assert_eq!(point.mul_ct(&scalar), &point * &scalar);
+            }
+            assert_eq!(
+                G2Point::<C>::point_at_infinity().mul_ct(&Scalar::new(5)),
+                G2Point::point_at_infinity()
+            );
+        }
+
+        check::<Bn128>();
+        check::<Bls12_381>();
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    jacobian::JacobianPoint, point_arithmetic::StaticCurve, CurvePoint, Scalar, WeierstrassCurve,
+};
+
+/// The number of scalar bits covered by each window of a [`GeneratorTable`].
+const WINDOW_BITS: u64 = 4;
+
+/// A precomputed table of multiples of a curve's generator for fast fixed-base multiplication.
+///
+/// The scalar is split into windows of 4 bits. For the `i`th window, the table contains the
+/// multiples `j * 16^i * G` for all `j` in `[1, 16)`, so a multiplication with the generator
+/// only needs one table lookup and one mixed addition per window, and no doublings at all.
+///
+/// Curves store their table in a lazily initialized static next to their generator and return it
+/// from [`WeierstrassCurve::generator_table`].
+#[derive(Debug)]
+pub struct GeneratorTable<C: WeierstrassCurve> {
+    windows: Vec<Vec<CurvePoint<C>>>,
+}
+
+impl<C: WeierstrassCurve> GeneratorTable<C> {
+    /// Builds the table for `generator`, covering scalars with as many bits as the group order.
+    pub fn new(generator: &CurvePoint<C>) -> Self {
+        let window_count = C::order().bits().div_ceil(WINDOW_BITS) as usize;
+        let multiples_per_window = (1 << WINDOW_BITS) - 1;
+
+        let mut points = Vec::with_capacity(window_count * multiples_per_window);
+        let mut base = JacobianPoint::from(generator);
+        for _ in 0..window_count {
+            let mut multiple = base.clone();
+            for _ in 0..multiples_per_window {
+                let next = multiple.add(&base);
+                points.push(multiple);
+                multiple = next;
+            }
+
+            // After the loop, multiple equals 16 * base.
+            base = multiple;
+        }
+
+        let windows = JacobianPoint::batch_to_affine(&points)
+            .chunks(multiples_per_window)
+            .map(<[CurvePoint<C>]>::to_vec)
+            .collect();
+
+        Self { windows }
+    }
+
+    /// Multiplies the generator of the table with `scalar`.
+    pub(crate) fn multiply(&self, scalar: &Scalar<C>) -> CurvePoint<C> {
+        let scalar = scalar.value();
+        let mut result = JacobianPoint::point_at_infinity(StaticCurve::<C>::new());
+
+        for (i, window) in self.windows.iter().enumerate() {
+            let digit = (0..WINDOW_BITS)
+                .filter(|bit| scalar.bit(i as u64 * WINDOW_BITS + bit))
+                .fold(0, |digit, bit| digit | 1 << bit);
+
+            if digit != 0 {
+                result = result.add_mixed(&window[digit - 1]);
+            }
+        }
+
+        result.to_affine()
+    }
+}
@@ -0,0 +1,361 @@
+use num::{bigint::Sign, BigInt};
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+
+use crate::{curves::Bls12_381, FieldElement, Fp2, G2Point};
+
+/// The coefficients of the rational maps that define the 3-isogeny from the curve used by
+/// [`map_to_curve`] to the twist of [`Bls12_381`], see Appendix E.3 of RFC 9380.
+struct IsogenyMap {
+    x_numerator: Vec<Fp2<Bls12_381>>,
+    x_denominator: Vec<Fp2<Bls12_381>>,
+    y_numerator: Vec<Fp2<Bls12_381>>,
+    y_denominator: Vec<Fp2<Bls12_381>>,
+}
+
+static ISOGENY_MAP: Lazy<IsogenyMap> = Lazy::new(|| {
+    let coefficients = |values: &[(&str, &str)]| {
+        values
+            .iter()
+            .map(|(c0, c1)| {
+                Fp2::new(
+                    FieldElement::new(BigInt::parse_bytes(c0.as_bytes(), 10).unwrap()),
+                    FieldElement::new(BigInt::parse_bytes(c1.as_bytes(), 10).unwrap()),
+                )
+            })
+            .collect()
+    };
+
+    // The coefficients are ordered by increasing degree.
+    IsogenyMap {
+        x_numerator: coefficients(&[
+            (
+                "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542",
+                "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542",
+            ),
+            (
+                "0",
+                "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706522",
+            ),
+            (
+                "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706526",
+                "1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853261",
+            ),
+            (
+                "3557697382419259905260257622876359250272784728834673675850718343221361467102966990615722337003569479144794908942033",
+                "0",
+            ),
+        ]),
+        x_denominator: coefficients(&[
+            (
+                "0",
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559715",
+            ),
+            (
+                "12",
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559775",
+            ),
+            ("1", "0"),
+        ]),
+        y_numerator: coefficients(&[
+            (
+                "3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558",
+                "3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558",
+            ),
+            (
+                "0",
+                "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235518",
+            ),
+            (
+                "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706524",
+                "1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853263",
+            ),
+            (
+                "2816510427748580758331037284777117739799287910327449993381818688383577828123182200904113516794492504322962636245776",
+                "0",
+            ),
+        ]),
+        y_denominator: coefficients(&[
+            (
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559355",
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559355",
+            ),
+            (
+                "0",
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559571",
+            ),
+            (
+                "18",
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559769",
+            ),
+            ("1", "0"),
+        ]),
+    }
+});
+
+/// The effective cofactor `h_eff` of `G2`, whose multiplication maps any point on the twist
+/// into `G2`.
+static G2_EFFECTIVE_COFACTOR: Lazy<BigInt> = Lazy::new(|| {
+    BigInt::parse_bytes(
+        b"bc69f08f2ee75b3584c6a0ea91b352888e2a8e9145ad7689986ff031508ffe1329c2f178731db956d82bf015d1212b02ec0ec69d7477c1ae954cbc06689f6a359894c0adebbf6b4e8020005aaa95551",
+        16,
+    )
+    .unwrap()
+});
+
+/// Expands `message` into `len` uniformly random bytes with `expand_message_xmd` over SHA-256
+/// as specified in Section 5.3.1 of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380).
+///
+/// The domain separation tag `dst` makes the output for different applications independent
+/// of each other.
+///
+/// ## Panics
+///
+/// Panics if `len` is larger than `255 * 32` or `dst` is longer than 255 bytes.
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    let block_count = len.div_ceil(32);
+    assert!(block_count <= 255, "len must not be larger than 255 * 32");
+    assert!(dst.len() <= 255, "dst must not be longer than 255 bytes");
+
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    let b_0 = Sha256::new()
+        .chain_update([0; 64])
+        .chain_update(message)
+        .chain_update((len as u16).to_be_bytes())
+        .chain_update([0])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut bytes = b_i.to_vec();
+    for i in 2..=block_count {
+        let xor = b_0.iter().zip(b_i).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+        b_i = Sha256::new()
+            .chain_update(xor)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        bytes.extend(b_i);
+    }
+
+    bytes.truncate(len);
+    bytes
+}
+
+/// Hashes `message` to a point of `G2` on the twist of [`Bls12_381`] with the suite
+/// `BLS12381G2_XMD:SHA-256_SSWU_RO_` of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380).
+///
+/// The result is indistinguishable from a random point and nobody knows its discrete logarithm
+/// with respect to the generator. The domain separation tag `dst` makes the output for different
+/// applications independent of each other.
+///
+/// ## Panics
+///
+/// Panics if `dst` is longer than 255 bytes.
+pub fn hash_to_g2(message: &[u8], dst: &[u8]) -> G2Point<Bls12_381> {
+    let [u0, u1] = hash_to_field(message, dst);
+    let point = map_to_curve(&u0) + &map_to_curve(&u1);
+
+    &point * &*G2_EFFECTIVE_COFACTOR
+}
+
+/// Hashes `message` to two elements of `Fp2` with `hash_to_field` from Section 5.2 of RFC 9380.
+fn hash_to_field(message: &[u8], dst: &[u8]) -> [Fp2<Bls12_381>; 2] {
+    // Each coordinate is derived from 64 bytes, which makes the bias of the reduction negligible.
+    let bytes = expand_message_xmd(message, dst, 2 * 2 * 64);
+    let element = |bytes: &[u8]| FieldElement::new(BigInt::from_bytes_be(Sign::Plus, bytes));
+
+    [0, 1].map(|i| {
+        let bytes = &bytes[128 * i..128 * (i + 1)];
+        Fp2::new(element(&bytes[..64]), element(&bytes[64..]))
+    })
+}
+
+/// Maps `u` to a point on the twist with the simplified SWU map for the isogenous curve
+/// `y^2 = x^3 + A x + B` with `A = 240u` and `B = 1012(1 + u)`, followed by the 3-isogeny
+/// to the twist, see Sections 6.6.2 and 6.6.3 of RFC 9380.
+///
+/// The result is not necessarily in `G2`.
+fn map_to_curve(u: &Fp2<Bls12_381>) -> G2Point<Bls12_381> {
+    let a = Fp2::new(FieldElement::zero(), FieldElement::new(240));
+    let b = Fp2::new(FieldElement::new(1012), FieldElement::new(1012));
+    let z = -Fp2::new(FieldElement::new(2), FieldElement::one());
+    let g = |x: &Fp2<Bls12_381>| x.square() * x + &(&a * x) + &b;
+
+    let z_u_squared = &z * &u.square();
+    let x1 = match (z_u_squared.square() + &z_u_squared).inverse() {
+        // SAFETY: A is not zero.
+        Some(tv1) => -(&b * &a.inverse().unwrap()) * &(tv1 + &Fp2::one()),
+        // SAFETY: Z and A are not zero.
+        None => &b * &(&z * &a).inverse().unwrap(),
+    };
+
+    // Either g(x1) or g(x2) = g(Z u^2 x1) is a square.
+    let (x, y) = match g(&x1).sqrt() {
+        Some(y) => (x1, y),
+        None => {
+            let x2 = z_u_squared * &x1;
+            // SAFETY: g(x1) is not a square, so g(x2) is.
+            let y = g(&x2).sqrt().unwrap();
+            (x2, y)
+        }
+    };
+    let y = if sgn0(u) == sgn0(&y) { y } else { -y };
+
+    isogeny_map(&x, &y)
+}
+
+/// Maps the point `(x, y)` on the isogenous curve to the twist.
+fn isogeny_map(x: &Fp2<Bls12_381>, y: &Fp2<Bls12_381>) -> G2Point<Bls12_381> {
+    let evaluate = |coefficients: &[Fp2<Bls12_381>]| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(Fp2::zero(), |result, coefficient| result * x + coefficient)
+    };
+
+    let map = &*ISOGENY_MAP;
+    // The denominators vanish only at points in the kernel of the isogeny.
+    let (Some(x_denominator), Some(y_denominator)) = (
+        evaluate(&map.x_denominator).inverse(),
+        evaluate(&map.y_denominator).inverse(),
+    ) else {
+        return G2Point::point_at_infinity();
+    };
+
+    G2Point::new_unchecked(
+        evaluate(&map.x_numerator) * &x_denominator,
+        y * &evaluate(&map.y_numerator) * &y_denominator,
+    )
+}
+
+/// Returns the sign of `x` as defined in Section 4.1 of RFC 9380, which is the parity of `c0`,
+/// or the parity of `c1` if `c0` is zero.
+fn sgn0(x: &Fp2<Bls12_381>) -> bool {
+    if x.c0().is_zero() {
+        x.c1().value().bit(0)
+    } else {
+        x.c0().value().bit(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::hex;
+
+    use super::*;
+
+    /// The messages of the test vectors of RFC 9380, where `q128_` and `a512_` are followed by
+    /// 128 and 512 repetitions of the letter.
+    fn test_messages() -> [Vec<u8>; 5] {
+        [
+            b"".to_vec(),
+            b"abc".to_vec(),
+            b"abcdef0123456789".to_vec(),
+            [b"q128_".as_slice(), &[b'q'; 128]].concat(),
+            [b"a512_".as_slice(), &[b'a'; 512]].concat(),
+        ]
+    }
+
+    /// Test vectors from Appendix K.1 of RFC 9380.
+    #[test]
+    fn expand_message_xmd_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let expected_short = [
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+            "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+        ];
+        let expected_long = [
+            concat!(
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe",
+                "e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18",
+                "eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc",
+                "c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+            concat!(
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a",
+                "647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635",
+                "bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00",
+                "058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+            concat!(
+                "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9",
+                "ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4b",
+                "c95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be1",
+                "4cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+            ),
+            concat!(
+                "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bb",
+                "d88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0",
+                "e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b",
+                "29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a",
+            ),
+            concat!(
+                "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d0",
+                "6d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608",
+                "ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4",
+                "a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487",
+            ),
+        ];
+
+        for ((message, short), long) in test_messages()
+            .iter()
+            .zip(expected_short)
+            .zip(expected_long)
+        {
+            assert_eq!(expand_message_xmd(message, dst, 0x20), hex(short));
+            assert_eq!(expand_message_xmd(message, dst, 0x80), hex(long));
+        }
+    }
+
+    /// Test vectors from Appendix J.10.1 of RFC 9380.
+    #[test]
+    fn hash_to_g2_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let expected = [
+            concat!(
+                "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+                "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+                "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+            ),
+            concat!(
+                "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+                "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+                "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+            ),
+            concat!(
+                "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c",
+                "121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0",
+                "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be",
+                "05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+            ),
+            concat!(
+                "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb91",
+                "19a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da",
+                "09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e5662",
+                "14f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+            ),
+            concat!(
+                "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d01569",
+                "01a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534",
+                "03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab52",
+                "0b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+            ),
+        ];
+
+        for (message, expected) in test_messages().iter().zip(expected) {
+            let point = hash_to_g2(message, dst);
+            assert!(point.is_in_subgroup());
+            assert_eq!(point.to_uncompressed().to_vec(), hex(expected));
+        }
+    }
+}
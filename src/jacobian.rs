@@ -0,0 +1,337 @@
+use crate::{
+    point_arithmetic::{CurveContext, StaticCurve},
+    CurvePoint, FieldRef, PrimeFieldElement, WeierstrassCurve,
+};
+
+/// A point in Jacobian projective coordinates `(X : Y : Z)`, which represents the affine point
+/// `(X / Z^2, Y / Z^3)`. Points with `Z = 0` represent the point at infinity.
+///
+/// Addition and doubling in this representation require no field inversions,
+/// only the conversion back to an affine point does.
+///
+/// Formulas taken from https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html.
+#[derive(Debug)]
+pub(crate) struct JacobianPoint<K: CurveContext> {
+    curve: K,
+    x: K::Element,
+    y: K::Element,
+    z: K::Element,
+}
+
+impl<K: CurveContext> Clone for JacobianPoint<K> {
+    fn clone(&self) -> Self {
+        Self {
+            curve: self.curve,
+            x: self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+}
+
+impl<C: WeierstrassCurve> From<&CurvePoint<C>> for JacobianPoint<StaticCurve<C>> {
+    fn from(point: &CurvePoint<C>) -> Self {
+        Self::from_affine(StaticCurve::new(), point)
+    }
+}
+
+impl<K: CurveContext> JacobianPoint<K>
+where
+    for<'a> &'a K::Element: FieldRef<K::Element>,
+{
+    /// Converts the affine `point` on `curve` into Jacobian coordinates.
+    pub(crate) fn from_affine(curve: K, point: &K::Point) -> Self {
+        match K::coordinates(point) {
+            None => Self::point_at_infinity(curve),
+            Some((x, y)) => Self {
+                curve,
+                x: x.clone(),
+                y: y.clone(),
+                z: curve.one(),
+            },
+        }
+    }
+
+    /// Returns the point at infinity, represented as `(1 : 1 : 0)`.
+    pub(crate) fn point_at_infinity(curve: K) -> Self {
+        Self {
+            curve,
+            x: curve.one(),
+            y: curve.one(),
+            z: curve.zero(),
+        }
+    }
+
+    /// Returns `true` if this is the point at infinity.
+    pub(crate) fn is_point_at_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Converts the point back to affine coordinates using a single field inversion.
+    pub(crate) fn to_affine(&self) -> K::Point {
+        let Some(z_inverse) = self.z.inverse() else {
+            return self.curve.point(None);
+        };
+
+        let z_inverse_squared = z_inverse.square();
+        let x = &self.x * &z_inverse_squared;
+        let y = &self.y * &(z_inverse_squared * &z_inverse);
+
+        self.curve.point(Some((x, y)))
+    }
+
+    /// Converts all `points` to affine coordinates using a single field inversion in total.
+    ///
+    /// Uses Montgomery's trick: the product of all `Z` coordinates is inverted once and the
+    /// individual inverses are recovered from it with a few multiplications each.
+    pub(crate) fn batch_to_affine(points: &[JacobianPoint<K>]) -> Vec<K::Point> {
+        let Some(first) = points.first() else {
+            return Vec::new();
+        };
+        let curve = first.curve;
+
+        // The ith entry is the product of the Z coordinates of the first i points,
+        // skipping points at infinity.
+        let mut products = Vec::with_capacity(points.len());
+        let mut product = curve.one();
+        for point in points {
+            products.push(product.clone());
+            if !point.is_point_at_infinity() {
+                product = product * &point.z;
+            }
+        }
+
+        // SAFETY: The product only contains non-zero factors, so it is invertible.
+        let mut inverse = product.inverse().unwrap();
+        let mut affine_points = vec![curve.point(None); points.len()];
+        for (i, point) in points.iter().enumerate().rev() {
+            if point.is_point_at_infinity() {
+                continue;
+            }
+
+            // inverse is the inverse of the product of the Z coordinates of the first i + 1 points.
+            let z_inverse = &inverse * &products[i];
+            inverse = inverse * &point.z;
+
+            let z_inverse_squared = z_inverse.square();
+            affine_points[i] = curve.point(Some((
+                &point.x * &z_inverse_squared,
+                &point.y * &(z_inverse_squared * &z_inverse),
+            )));
+        }
+
+        affine_points
+    }
+
+    /// Doubles the point using the `dbl-2007-bl` formulas.
+    pub(crate) fn double(&self) -> Self {
+        if self.is_point_at_infinity() {
+            return self.clone();
+        }
+
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let yyyy = yy.square();
+        let zz = self.z.square();
+
+        let s = (&self.x + &yy).square() - &xx - &yyyy;
+        let s = &s + &s;
+
+        let mut m = &xx + &xx + &xx;
+        let a = self.curve.a();
+        if !a.is_zero() {
+            m = m + &(a * &zz.square());
+        }
+
+        let x = m.square() - &s - &s;
+
+        let eight_yyyy = &yyyy + &yyyy;
+        let eight_yyyy = &eight_yyyy + &eight_yyyy;
+        let eight_yyyy = &eight_yyyy + &eight_yyyy;
+        let y = m * &(s - &x) - &eight_yyyy;
+
+        // For points with y = 0 this results in z = 0, i.e. the point at infinity.
+        let z = (&self.y + &self.z).square() - &yy - &zz;
+
+        Self {
+            curve: self.curve,
+            x,
+            y,
+            z,
+        }
+    }
+
+    /// Adds `other` to `self` using the `add-2007-bl` formulas.
+    pub(crate) fn add(&self, other: &JacobianPoint<K>) -> Self {
+        if self.is_point_at_infinity() {
+            return other.clone();
+        }
+        if other.is_point_at_infinity() {
+            return self.clone();
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = &self.x * &z2z2;
+        let u2 = &other.x * &z1z1;
+        let s1 = &self.y * &other.z * &z2z2;
+        let s2 = &other.y * &self.z * &z1z1;
+
+        let h = u2 - &u1;
+        let r = &s2 - &s1;
+        if h.is_zero() {
+            // Both points have the same affine x-coordinate, so they are either equal
+            // or each other's negation.
+            if r.is_zero() {
+                return self.double();
+            }
+            return Self::point_at_infinity(self.curve);
+        }
+
+        let i = (&h + &h).square();
+        let j = &h * &i;
+        let r = &r + &r;
+        let v = u1 * &i;
+
+        let x = r.square() - &j - &v - &v;
+        let s1_j = s1 * &j;
+        let y = r * &(v - &x) - &s1_j - &s1_j;
+        let z = ((&self.z + &other.z).square() - &z1z1 - &z2z2) * &h;
+
+        Self {
+            curve: self.curve,
+            x,
+            y,
+            z,
+        }
+    }
+
+    /// Adds the affine point `other` to `self` using the `madd-2007-bl` formulas,
+    /// which are cheaper than a general addition since `other` has an implicit `Z = 1`.
+    pub(crate) fn add_mixed(&self, other: &K::Point) -> Self {
+        let Some((x2, y2)) = K::coordinates(other) else {
+            return self.clone();
+        };
+        if self.is_point_at_infinity() {
+            return Self::from_affine(self.curve, other);
+        }
+
+        let z1z1 = self.z.square();
+        let u2 = x2 * &z1z1;
+        let s2 = y2 * &self.z * &z1z1;
+
+        let h = u2 - &self.x;
+        let r = s2 - &self.y;
+        if h.is_zero() {
+            if r.is_zero() {
+                return self.double();
+            }
+            return Self::point_at_infinity(self.curve);
+        }
+
+        let hh = h.square();
+        let i = &hh + &hh;
+        let i = &i + &i;
+        let j = &h * &i;
+        let r = &r + &r;
+        let v = &self.x * &i;
+
+        let x = r.square() - &j - &v - &v;
+        let y1_j = &self.y * &j;
+        let y = r * &(v - &x) - &y1_j - &y1_j;
+        let z = (&self.z + &h).square() - &z1z1 - &hh;
+
+        Self {
+            curve: self.curve,
+            x,
+            y,
+            z,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::{
+        curves::{Bn128, PrimeOrderTestCurve, Secp256k1, TestCurve},
+        Scalar,
+    };
+
+    use super::*;
+
+    fn check_against_affine_arithmetic<C: WeierstrassCurve + std::fmt::Debug>(
+        points: &[CurvePoint<C>],
+    ) {
+        for p in points {
+            let p_jacobian = JacobianPoint::from(p);
+
+            assert_eq!(p_jacobian.to_affine(), *p);
+            assert_eq!(p_jacobian.double().to_affine(), p + p);
+
+            for q in points {
+                let q_jacobian = JacobianPoint::from(q);
+                // Bring q into a representation with Z != 1 to exercise the general formulas.
+                let q_scaled = q_jacobian.double().add_mixed(&-q);
+
+                assert_eq!(p_jacobian.add(&q_jacobian).to_affine(), p + q);
+                assert_eq!(p_jacobian.add(&q_scaled).to_affine(), p + q);
+                assert_eq!(q_scaled.add_mixed(p).to_affine(), p + q);
+            }
+        }
+    }
+
+    #[test]
+    fn batch_conversion_to_affine() {
+        let points = (0..12)
+            .map(|scalar| TestCurve::generator() * &BigInt::from(scalar))
+            .map(|point| JacobianPoint::from(&point).double().add_mixed(&-point))
+            .collect::<Vec<_>>();
+
+        let expected = points
+            .iter()
+            .map(JacobianPoint::to_affine)
+            .collect::<Vec<_>>();
+
+        assert_eq!(JacobianPoint::batch_to_affine(&points), expected);
+        assert!(JacobianPoint::<StaticCurve<TestCurve>>::batch_to_affine(&[]).is_empty());
+    }
+
+    #[test]
+    fn jacobian_arithmetic_test_curve() {
+        let points = (0..12)
+            .map(|scalar| TestCurve::generator() * &BigInt::from(scalar))
+            .collect::<Vec<_>>();
+
+        check_against_affine_arithmetic(&points);
+    }
+
+    #[test]
+    fn jacobian_arithmetic_prime_order_test_curve() {
+        let points = (0..83)
+            .step_by(7)
+            .map(|scalar| PrimeOrderTestCurve::generator() * &BigInt::from(scalar))
+            .collect::<Vec<_>>();
+
+        check_against_affine_arithmetic(&points);
+    }
+
+    #[test]
+    fn jacobian_arithmetic_bn128() {
+        let mut rng = rand::thread_rng();
+        let p = Bn128::generator() * &Scalar::random(&mut rng);
+        let q = Bn128::generator() * &Scalar::random(&mut rng);
+
+        check_against_affine_arithmetic(&[p.clone(), -&p, q, CurvePoint::point_at_infinity()]);
+    }
+
+    #[test]
+    fn jacobian_arithmetic_secp256k1() {
+        let mut rng = rand::thread_rng();
+        let p = Secp256k1::generator() * &Scalar::random(&mut rng);
+        let q = Secp256k1::generator() * &Scalar::random(&mut rng);
+
+        check_against_affine_arithmetic(&[p.clone(), -&p, q, CurvePoint::point_at_infinity()]);
+    }
+}
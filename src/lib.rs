@@ -1,10 +1,48 @@
+pub mod bls;
 mod curve_point;
 pub mod curves;
+mod dynamic_curve;
+pub mod ecdsa;
+mod endomorphism;
+mod error;
+pub mod ethereum;
 mod extended_euclidean;
+mod extension_field;
+mod field_element;
+mod g2_point;
+mod generator_table;
+mod hash_to_curve;
+mod jacobian;
+mod montgomery;
+mod msm;
 mod multiplicative_inverse;
+mod pairing;
+mod point_arithmetic;
+pub mod precompiles;
+mod projective;
+mod rfc6979;
+mod scalar;
+pub mod schnorr;
+mod square_root;
+#[cfg(test)]
+mod test_utils;
 mod weierstrass_curve;
+mod wnaf;
 
 pub use curve_point::*;
+pub use dynamic_curve::*;
+pub use endomorphism::*;
+pub use error::*;
 pub use extended_euclidean::*;
+pub use extension_field::*;
+pub use field_element::*;
+pub use g2_point::*;
+pub use generator_table::*;
+pub use hash_to_curve::*;
+pub use montgomery::MontgomeryParameters;
 pub use multiplicative_inverse::*;
+pub use pairing::*;
+pub use rfc6979::*;
+pub use scalar::*;
+pub use square_root::*;
 pub use weierstrass_curve::*;
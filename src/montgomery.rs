@@ -0,0 +1,286 @@
+use num::{bigint::Sign, BigInt};
+
+/// The maximum number of 64-bit limbs of a modulus, which is enough for the 521-bit modulus of
+/// [`P521`](crate::curves::P521).
+pub(crate) const MAX_LIMBS: usize = 9;
+
+/// A residue in little-endian 64-bit limbs. Only as many limbs as the modulus needs are used,
+/// the others are always zero.
+pub(crate) type Limbs = [u64; MAX_LIMBS];
+
+/// Precomputed constants for arithmetic in Montgomery form modulo an odd `p`.
+///
+/// With `R = 2^(64 * n)` for the number of limbs `n` of `p`, the Montgomery form of `a` is
+/// `a * R mod p`. The product of two elements in Montgomery form can be reduced with Montgomery's
+/// REDC algorithm, which only needs multiplications and shifts of limbs, instead of a division
+/// by `p`. Additions and subtractions are the same as for the regular form.
+///
+/// Curves store their parameters in a lazily initialized static next to their field modulus and
+/// return them from [`WeierstrassCurve::montgomery_parameters`](crate::WeierstrassCurve::montgomery_parameters).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryParameters {
+    modulus: Limbs,
+    len: usize,
+    /// `-p^-1 mod 2^64`.
+    inverse: u64,
+    /// `R^2 mod p`, which converts into Montgomery form with a single multiplication.
+    r_squared: Limbs,
+    /// `R mod p`, i.e. one in Montgomery form.
+    one: Limbs,
+}
+
+impl MontgomeryParameters {
+    /// Precomputes the constants for the given `modulus`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `modulus` is even, smaller than three or has more than `64 * 9` bits.
+    pub fn new(modulus: &BigInt) -> Self {
+        assert!(
+            modulus > &BigInt::from(2) && modulus.bit(0),
+            "modulus must be odd and larger than two"
+        );
+        let len = modulus.bits().div_ceil(64) as usize;
+        assert!(len <= MAX_LIMBS, "modulus must have at most 576 bits");
+
+        let modulus_limbs = to_limbs(modulus);
+
+        // Newton's iteration doubles the number of correct low bits of p^-1 in every step,
+        // starting with 1 correct bit, since p is odd.
+        let mut inverse = 1u64;
+        for _ in 0..6 {
+            inverse =
+                inverse.wrapping_mul(2u64.wrapping_sub(modulus_limbs[0].wrapping_mul(inverse)));
+        }
+
+        let r = BigInt::from(1) << (64 * len);
+        Self {
+            modulus: modulus_limbs,
+            len,
+            inverse: inverse.wrapping_neg(),
+            r_squared: to_limbs(&(&r * &r % modulus)),
+            one: to_limbs(&(r % modulus)),
+        }
+    }
+
+    /// Returns the modulus `p`.
+    pub(crate) fn modulus(&self) -> BigInt {
+        from_limbs(&self.modulus)
+    }
+
+    /// Returns one in Montgomery form.
+    pub(crate) fn one(&self) -> Limbs {
+        self.one
+    }
+
+    /// Converts the `value` in the range `[0, p)` into Montgomery form.
+    pub(crate) fn encode(&self, value: &BigInt) -> Limbs {
+        self.mul(&to_limbs(value), &self.r_squared)
+    }
+
+    /// Converts `a` out of Montgomery form.
+    pub(crate) fn decode(&self, a: &Limbs) -> BigInt {
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+        from_limbs(&self.mul(a, &one))
+    }
+
+    /// Computes `a + b mod p`.
+    pub(crate) fn add(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let (sum, carry) = add_limbs(a, b, self.len);
+        if carry || !less_than(&sum, &self.modulus, self.len) {
+            sub_limbs(&sum, &self.modulus, self.len).0
+        } else {
+            sum
+        }
+    }
+
+    /// Computes `a - b mod p`.
+    pub(crate) fn sub(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let (difference, borrow) = sub_limbs(a, b, self.len);
+        if borrow {
+            add_limbs(&difference, &self.modulus, self.len).0
+        } else {
+            difference
+        }
+    }
+
+    /// Computes `-a mod p`.
+    pub(crate) fn neg(&self, a: &Limbs) -> Limbs {
+        self.sub(&[0; MAX_LIMBS], a)
+    }
+
+    /// Computes `a * b * R^-1 mod p`, i.e. the product of elements in Montgomery form, using
+    /// the coarsely integrated operand scanning (CIOS) variant of REDC.
+    ///
+    /// See Çetin Kaya Koç, Tolga Acar and Burton S. Kaliski Jr.,
+    /// [Analyzing and Comparing Montgomery Multiplication Algorithms](https://doi.org/10.1109/40.502403).
+    pub(crate) fn mul(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let n = self.len;
+        let mut t = [0u64; MAX_LIMBS + 2];
+
+        for &b_i in &b[..n] {
+            // t = t + a * b_i.
+            let mut carry = 0;
+            for j in 0..n {
+                (t[j], carry) = mac(t[j], a[j], b_i, carry);
+            }
+            let (sum, overflow) = t[n].overflowing_add(carry);
+            t[n] = sum;
+            t[n + 1] = overflow as u64;
+
+            // t = (t + m * p) / 2^64, where m is chosen such that the division is exact.
+            let m = t[0].wrapping_mul(self.inverse);
+            let (_, mut carry) = mac(t[0], m, self.modulus[0], 0);
+            for j in 1..n {
+                (t[j - 1], carry) = mac(t[j], m, self.modulus[j], carry);
+            }
+            let (sum, overflow) = t[n].overflowing_add(carry);
+            t[n - 1] = sum;
+            t[n] = t[n + 1] + overflow as u64;
+        }
+
+        // The result is smaller than 2p, so a single subtraction reduces it.
+        let mut result = [0; MAX_LIMBS];
+        result[..n].copy_from_slice(&t[..n]);
+        if t[n] != 0 || !less_than(&result, &self.modulus, n) {
+            result = sub_limbs(&result, &self.modulus, n).0;
+        }
+        result
+    }
+}
+
+/// Computes `acc + a * b + carry` and returns the low and the high limb of the result.
+fn mac(acc: u64, a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let wide = acc as u128 + a as u128 * b as u128 + carry as u128;
+    (wide as u64, (wide >> 64) as u64)
+}
+
+/// Adds the lowest `n` limbs of `a` and `b` and returns the sum and whether it overflowed.
+fn add_limbs(a: &Limbs, b: &Limbs, n: usize) -> (Limbs, bool) {
+    let mut sum = [0; MAX_LIMBS];
+    let mut carry = false;
+    for i in 0..n {
+        let (partial, overflow_1) = a[i].overflowing_add(b[i]);
+        let (partial, overflow_2) = partial.overflowing_add(carry as u64);
+        sum[i] = partial;
+        carry = overflow_1 || overflow_2;
+    }
+    (sum, carry)
+}
+
+/// Subtracts the lowest `n` limbs of `b` from `a` and returns the difference and whether it
+/// underflowed.
+fn sub_limbs(a: &Limbs, b: &Limbs, n: usize) -> (Limbs, bool) {
+    let mut difference = [0; MAX_LIMBS];
+    let mut borrow = false;
+    for i in 0..n {
+        let (partial, underflow_1) = a[i].overflowing_sub(b[i]);
+        let (partial, underflow_2) = partial.overflowing_sub(borrow as u64);
+        difference[i] = partial;
+        borrow = underflow_1 || underflow_2;
+    }
+    (difference, borrow)
+}
+
+/// Returns `true` if the number in the lowest `n` limbs of `a` is smaller than that of `b`.
+fn less_than(a: &Limbs, b: &Limbs, n: usize) -> bool {
+    for i in (0..n).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// Converts the non-negative `value` with at most `64 * MAX_LIMBS` bits into limbs.
+fn to_limbs(value: &BigInt) -> Limbs {
+    let mut limbs = [0; MAX_LIMBS];
+    for (limb, digit) in limbs.iter_mut().zip(value.iter_u64_digits()) {
+        *limb = digit;
+    }
+    limbs
+}
+
+/// Converts the limbs into a non-negative integer.
+fn from_limbs(limbs: &Limbs) -> BigInt {
+    let bytes = limbs
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect::<Vec<_>>();
+    BigInt::from_bytes_le(Sign::Plus, &bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use num::traits::Euclid;
+    use rand::RngCore;
+
+    use crate::{
+        curves::{Bls12_381, P521},
+        WeierstrassCurve,
+    };
+
+    use super::*;
+
+    /// Test the arithmetic against `BigInt` for moduli with one, four, six and nine limbs,
+    /// including values close to the modulus.
+    #[test]
+    fn matches_bigint_arithmetic() {
+        fn check(modulus: BigInt) {
+            let parameters = MontgomeryParameters::new(&modulus);
+            let values = [
+                BigInt::ZERO,
+                BigInt::from(1),
+                BigInt::from(2),
+                &modulus - 1,
+                &modulus - 2,
+                &modulus >> 1,
+                Euclid::rem_euclid(&BigInt::from(u128::MAX), &modulus),
+            ]
+            .into_iter()
+            .chain((0..5).map(|_| {
+                let mut bytes = [0; 72];
+                rand::thread_rng().fill_bytes(&mut bytes);
+                Euclid::rem_euclid(&BigInt::from_bytes_le(Sign::Plus, &bytes), &modulus)
+            }));
+            let values = values.collect::<Vec<_>>();
+
+            for a in &values {
+                let a_montgomery = parameters.encode(a);
+                assert_eq!(&parameters.decode(&a_montgomery), a);
+                assert_eq!(
+                    parameters.decode(&parameters.neg(&a_montgomery)),
+                    Euclid::rem_euclid(&-a, &modulus)
+                );
+
+                for b in &values {
+                    let b_montgomery = parameters.encode(b);
+                    let result = |limbs: Limbs| parameters.decode(&limbs);
+                    let expected = |value: BigInt| Euclid::rem_euclid(&value, &modulus);
+
+                    assert_eq!(
+                        result(parameters.add(&a_montgomery, &b_montgomery)),
+                        expected(a + b)
+                    );
+                    assert_eq!(
+                        result(parameters.sub(&a_montgomery, &b_montgomery)),
+                        expected(a - b)
+                    );
+                    assert_eq!(
+                        result(parameters.mul(&a_montgomery, &b_montgomery)),
+                        expected(a * b)
+                    );
+                }
+            }
+
+            assert_eq!(parameters.decode(&parameters.one()), BigInt::from(1));
+        }
+
+        check(BigInt::from(11));
+        check(BigInt::from(u64::MAX - 58));
+        check((BigInt::from(1) << 255) - 19);
+        check(Bls12_381::field_modulus());
+        check(P521::field_modulus());
+    }
+}
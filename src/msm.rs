@@ -0,0 +1,281 @@
+use num::BigInt;
+
+use crate::{
+    jacobian::JacobianPoint, point_arithmetic::StaticCurve, wnaf::wnaf, CurvePoint, Scalar,
+    WeierstrassCurve,
+};
+
+/// The number of terms up to which Straus' method is used instead of Pippenger's.
+/// On 256-bit curves, Pippenger's method starts to be faster somewhere between 64 and 128 terms.
+const STRAUS_THRESHOLD: usize = 64;
+
+/// The wNAF window width used for each term in Straus' method.
+const STRAUS_WINDOW: usize = 4;
+
+/// Computes `sum(k_i * P_i)` for all terms `(k_i, P_i)`,
+/// picking the faster of Straus' and Pippenger's method for the number of terms.
+pub(crate) fn multi_scalar_mul<C: WeierstrassCurve>(
+    terms: &[(Scalar<C>, CurvePoint<C>)],
+) -> CurvePoint<C> {
+    if terms.len() <= STRAUS_THRESHOLD {
+        straus(terms)
+    } else {
+        pippenger(terms)
+    }
+}
+
+/// Straus' (or Shamir's) method: all scalars are recoded in wNAF and processed in a single
+/// interleaved pass, so the doublings are shared between all terms.
+///
+/// Each term needs its own table of odd multiples, which makes this the better choice
+/// for a small number of terms.
+pub(crate) fn straus<C: WeierstrassCurve>(terms: &[(Scalar<C>, CurvePoint<C>)]) -> CurvePoint<C> {
+    let digits = terms
+        .iter()
+        .map(|(scalar, _)| wnaf(scalar.value(), STRAUS_WINDOW))
+        .collect::<Vec<_>>();
+
+    straus_with_digits(&digits, terms.iter().map(|(_, point)| point))
+}
+
+/// Straus' method for arbitrary non-negative integers, which are not reduced modulo the group
+/// order. This is used for the two half-length scalars of the GLV method.
+pub(crate) fn straus_unreduced<C: WeierstrassCurve>(
+    terms: &[(BigInt, CurvePoint<C>)],
+) -> CurvePoint<C> {
+    let digits = terms
+        .iter()
+        .map(|(scalar, _)| wnaf(scalar, STRAUS_WINDOW))
+        .collect::<Vec<_>>();
+
+    straus_with_digits(&digits, terms.iter().map(|(_, point)| point))
+}
+
+/// Computes `sum(k_i * P_i)` from the wNAF `digits` of each `k_i` and the points `P_i`.
+fn straus_with_digits<'a, C: WeierstrassCurve>(
+    digits: &[Vec<i32>],
+    points: impl ExactSizeIterator<Item = &'a CurvePoint<C>>,
+) -> CurvePoint<C> {
+    let table_size = 1 << (STRAUS_WINDOW - 2);
+
+    // The tables of all terms are stored back to back and converted to affine coordinates
+    // together. The jth entry of the ith table is (2j + 1) * P_i.
+    let mut tables = Vec::with_capacity(points.len() * table_size);
+    for point in points {
+        let point = JacobianPoint::from(point);
+        let point_doubled = point.double();
+        let mut multiple = point;
+        for _ in 0..table_size {
+            let next = multiple.add(&point_doubled);
+            tables.push(multiple);
+            multiple = next;
+        }
+    }
+    let tables = JacobianPoint::batch_to_affine(&tables);
+
+    let max_len = digits.iter().map(Vec::len).max().unwrap_or(0);
+    let mut result = JacobianPoint::point_at_infinity(StaticCurve::<C>::new());
+    for position in (0..max_len).rev() {
+        result = result.double();
+
+        for (i, digits) in digits.iter().enumerate() {
+            let Some(&digit) = digits.get(position) else {
+                continue;
+            };
+
+            let entry = &tables[i * table_size + (digit.unsigned_abs() / 2) as usize];
+            if digit > 0 {
+                result = result.add_mixed(entry);
+            } else if digit < 0 {
+                result = result.add_mixed(&entry.negate());
+            }
+        }
+    }
+
+    result.to_affine()
+}
+
+/// Pippenger's bucket method: the scalars are recoded into signed windows of `c` bits and for
+/// each window, every point is added to (or subtracted from) the bucket indexed by the absolute
+/// value of its digit. The buckets are then combined with a running sum, so each window costs
+/// roughly one addition per term plus `2^c` additions, independent of the number of terms.
+pub(crate) fn pippenger<C: WeierstrassCurve>(
+    terms: &[(Scalar<C>, CurvePoint<C>)],
+) -> CurvePoint<C> {
+    let window_bits = pippenger_window_bits(terms.len(), C::order().bits());
+    let digits = terms
+        .iter()
+        .map(|(scalar, _)| signed_window_digits(scalar.value(), window_bits))
+        .collect::<Vec<_>>();
+    let window_count = digits.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut result = JacobianPoint::point_at_infinity(StaticCurve::<C>::new());
+    for window in (0..window_count).rev() {
+        for _ in 0..window_bits {
+            result = result.double();
+        }
+
+        // The ith bucket accumulates the points whose digit has the absolute value i + 1.
+        let mut buckets =
+            vec![JacobianPoint::point_at_infinity(StaticCurve::<C>::new()); 1 << (window_bits - 1)];
+        for ((_, point), digits) in terms.iter().zip(&digits) {
+            let Some(&digit) = digits.get(window) else {
+                continue;
+            };
+
+            let index = digit.unsigned_abs() as usize;
+            if digit > 0 {
+                buckets[index - 1] = buckets[index - 1].add_mixed(point);
+            } else if digit < 0 {
+                buckets[index - 1] = buckets[index - 1].add_mixed(&point.negate());
+            }
+        }
+
+        // Computes sum((i + 1) * bucket_i) as a sum of running sums:
+        // bucket_n + (bucket_n + bucket_{n-1}) + ... + (bucket_n + ... + bucket_1).
+        let mut running_sum = JacobianPoint::point_at_infinity(StaticCurve::<C>::new());
+        let mut window_sum = JacobianPoint::point_at_infinity(StaticCurve::<C>::new());
+        for bucket in buckets.iter().rev() {
+            running_sum = running_sum.add(bucket);
+            window_sum = window_sum.add(&running_sum);
+        }
+
+        result = result.add(&window_sum);
+    }
+
+    result.to_affine()
+}
+
+/// Recodes the non-negative `scalar` into digits in the range `[-2^(c - 1), 2^(c - 1)]`,
+/// ordered from least to most significant, such that `scalar = sum(digits[i] * 2^(c * i))`.
+fn signed_window_digits(scalar: &BigInt, window_bits: usize) -> Vec<i64> {
+    let half_window = 1i64 << (window_bits - 1);
+    let window_count = (scalar.bits() as usize + 1).div_ceil(window_bits);
+
+    let mut digits = Vec::with_capacity(window_count);
+    let mut carry = 0;
+    for window in 0..window_count {
+        let mut digit = (0..window_bits)
+            .filter(|bit| scalar.bit((window * window_bits + bit) as u64))
+            .fold(carry, |digit, bit| digit + (1 << bit));
+
+        carry = 0;
+        if digit > half_window {
+            digit -= 1 << window_bits;
+            carry = 1;
+        }
+        digits.push(digit);
+    }
+
+    digits
+}
+
+/// Returns the window size in bits that minimizes the estimated number of additions
+/// `(bits / c) * (n + 2^c)` of Pippenger's method for `n` terms.
+fn pippenger_window_bits(term_count: usize, scalar_bits: u64) -> usize {
+    (2..=16)
+        .min_by_key(|window_bits| {
+            scalar_bits.div_ceil(*window_bits as u64) * (term_count as u64 + (1 << window_bits))
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::{Bn128, PrimeOrderTestCurve, Secp256k1};
+
+    use super::*;
+
+    fn random_terms<C: WeierstrassCurve>(count: usize) -> Vec<(Scalar<C>, CurvePoint<C>)> {
+        let mut rng = rand::thread_rng();
+        (0..count)
+            .map(|_| {
+                (
+                    Scalar::random(&mut rng),
+                    CurvePoint::generator_mul(&Scalar::random(&mut rng)),
+                )
+            })
+            .collect()
+    }
+
+    fn naive_sum<C: WeierstrassCurve>(terms: &[(Scalar<C>, CurvePoint<C>)]) -> CurvePoint<C> {
+        terms
+            .iter()
+            .fold(CurvePoint::point_at_infinity(), |sum, (scalar, point)| {
+                sum + &(point * scalar)
+            })
+    }
+
+    fn check_methods<C: WeierstrassCurve + std::fmt::Debug>(terms: &[(Scalar<C>, CurvePoint<C>)]) {
+        let expected = naive_sum(terms);
+        assert_eq!(straus(terms), expected);
+        assert_eq!(pippenger(terms), expected);
+        assert_eq!(CurvePoint::multi_scalar_mul(terms), expected);
+    }
+
+    #[test]
+    fn signed_window_recoding() {
+        for window_bits in 2..=10 {
+            let scalar = Scalar::<Secp256k1>::random(&mut rand::thread_rng());
+            let digits = signed_window_digits(scalar.value(), window_bits);
+
+            let recomposed = digits
+                .iter()
+                .rev()
+                .fold(BigInt::ZERO, |acc, digit| (acc << window_bits) + digit);
+            assert_eq!(&recomposed, scalar.value());
+            assert!(digits
+                .iter()
+                .all(|digit| digit.unsigned_abs() <= 1 << (window_bits - 1)));
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        check_methods::<Secp256k1>(&[]);
+    }
+
+    #[test]
+    fn edge_cases() {
+        let point = Secp256k1::generator() * &Scalar::new(42);
+        let scalar = Scalar::<Secp256k1>::new(1234567);
+
+        check_methods(&[
+            (Scalar::zero(), point.clone()),
+            (scalar.clone(), CurvePoint::point_at_infinity()),
+            (scalar.clone(), point.clone()),
+            (scalar.clone(), point.clone()),
+            (scalar.clone(), -&point),
+            (-scalar.clone(), point.clone()),
+            (Scalar::one(), point.clone()),
+        ]);
+
+        // Every combination of small scalars and points on a small curve.
+        let terms = (0..83)
+            .step_by(5)
+            .flat_map(|i| {
+                (0..83).step_by(11).map(move |j| {
+                    (
+                        Scalar::new(i),
+                        PrimeOrderTestCurve::generator() * &Scalar::new(j),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        check_methods::<PrimeOrderTestCurve>(&terms);
+    }
+
+    #[test]
+    fn random_terms_bn128() {
+        for count in [1, 2, 5, 40] {
+            check_methods::<Bn128>(&random_terms(count));
+        }
+    }
+
+    #[test]
+    fn random_terms_secp256k1() {
+        for count in [1, 3, 33, 70] {
+            check_methods::<Secp256k1>(&random_terms(count));
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use num::BigInt;
+
+use crate::{
+    CurvePoint, FieldElement, Fp12, Fp2, Fp6, FrobeniusCoefficients, G2Point, WeierstrassCurve,
+};
+
+/// The family of a pairing-friendly curve, which determines the shape of the optimal ate pairing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingFamily {
+    /// Barreto-Naehrig curves, whose Miller loop runs over `6u + 2` and ends with two additional
+    /// lines through the images of `Q` under the Frobenius map.
+    Bn,
+    /// Barreto-Lynn-Scott curves with embedding degree 12, whose Miller loop runs over `x`.
+    Bls12,
+}
+
+/// The type of the sextic twist on which the points of `G2` are represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwistType {
+    /// The divisive twist `y^2 = x^3 + b / ξ`.
+    D,
+    /// The multiplicative twist `y^2 = x^3 + b * ξ`.
+    M,
+}
+
+/// Parameter definitions for pairing-friendly curves with embedding degree 12 from the
+/// [BN and BLS12 families](PairingFamily).
+///
+/// The pairing maps a point `P` of the curve's subgroup `G1` and a point `Q` of the subgroup
+/// `G2` of the [sextic twist](G2Point) over [`Fp2`] into the `r`-th roots of unity in [`Fp12`].
+/// The curve parameter `a` must be zero.
+pub trait PairingCurve: WeierstrassCurve + Sized {
+    /// Returns the non-residue `ξ` in `Fp2` that defines the field extensions
+    /// `Fp6 = Fp2[v] / (v^3 - ξ)` and `Fp12 = Fp6[w] / (w^2 - v)`.
+    fn non_residue() -> Fp2<Self>;
+    /// Returns the family of the curve.
+    fn family() -> PairingFamily;
+    /// Returns the type of the twist, which is `y^2 = x^3 + b / ξ` for a
+    /// [D-type](TwistType::D) twist and `y^2 = x^3 + b * ξ` for an [M-type](TwistType::M) twist.
+    fn twist_type() -> TwistType;
+    /// Returns the generator of the subgroup `G2` of the twist.
+    fn g2_generator() -> G2Point<Self>;
+    /// Returns the precomputed coefficients used by [`Fp12::frobenius_map`] and
+    /// [`G2Point::frobenius_map`].
+    fn frobenius_coefficients() -> &'static FrobeniusCoefficients<Self>;
+    /// Returns the loop count of the optimal ate pairing, where `u` respectively `x` is the
+    /// parameter from which the curve's field modulus and order are derived.
+    ///
+    /// This is `6u + 2` for BN curves and `x` for BLS12 curves and may be negative.
+    fn ate_loop_count() -> BigInt;
+}
+
+/// Computes the optimal ate pairing `e(P, Q)` of the points `p` in `G1` and `q` in `G2`.
+///
+/// The pairing is bilinear, i.e. `e(aP, bQ) = e(P, Q)^(ab)`, and non-degenerate, i.e.
+/// `e(P, Q) != 1` if neither point is the point at infinity. The points are expected to be
+/// in their respective subgroups.
+pub fn pairing<C: PairingCurve>(p: &CurvePoint<C>, q: &G2Point<C>) -> Fp12<C> {
+    final_exponentiation(&miller_loop(p, q))
+}
+
+/// Computes the product `e(P_1, Q_1) * ... * e(P_n, Q_n)` of the pairings of all `pairs`.
+///
+/// This is considerably faster than computing the pairings one by one, since the expensive
+/// final exponentiation is only done once for the product of all Miller loops.
+pub fn multi_pairing<C: PairingCurve>(pairs: &[(CurvePoint<C>, G2Point<C>)]) -> Fp12<C> {
+    let product = pairs
+        .iter()
+        .fold(Fp12::one(), |product, (p, q)| product * &miller_loop(p, q));
+
+    final_exponentiation(&product)
+}
+
+/// Returns `true` if the product of the pairings of all `pairs` is one.
+///
+/// This is how pairing-based protocols usually verify equations, e.g. `e(P_1, Q_1) = e(P_2, Q_2)`
+/// holds if and only if `e(P_1, Q_1) * e(-P_2, Q_2) = 1`.
+pub fn pairing_check<C: PairingCurve>(pairs: &[(CurvePoint<C>, G2Point<C>)]) -> bool {
+    multi_pairing(pairs) == Fp12::one()
+}
+
+/// Computes the Miller loop of the optimal ate pairing, whose result still has to be raised to
+/// the power of `(p^12 - 1) / r` with [`final_exponentiation`].
+///
+/// The line functions are evaluated on the twist, i.e. `Q` is never mapped to the curve over
+/// `Fp12`, and vertical lines are skipped since they are eliminated by the final exponentiation.
+pub fn miller_loop<C: PairingCurve>(p: &CurvePoint<C>, q: &G2Point<C>) -> Fp12<C> {
+    let (Some(p), Some(_)) = (p.as_coordinates(), q.as_coordinates()) else {
+        return Fp12::one();
+    };
+
+    let loop_count = C::ate_loop_count();
+    let mut f = Fp12::one();
+    let mut t = q.clone();
+
+    // The most significant bit is covered by initializing T with Q.
+    let magnitude = loop_count.magnitude();
+    for bit in (0..magnitude.bits() - 1).rev() {
+        f = f.square() * &line_function(&t, &t, p);
+        t = t.double();
+
+        if magnitude.bit(bit) {
+            f = f * &line_function(&t, q, p);
+            t = t + q;
+        }
+    }
+
+    // The loop computed the function for |loop_count| and T = |loop_count| * Q. For a negative
+    // loop count, the function is inverted, which is the same as conjugating it after the final
+    // exponentiation.
+    if loop_count < BigInt::ZERO {
+        f = f.conjugate();
+        t = -t;
+    }
+
+    if C::family() == PairingFamily::Bls12 {
+        return f;
+    }
+
+    // Add the lines through T and ψ(Q), and through T + ψ(Q) and -ψ^2(Q).
+    let q1 = q.frobenius_map();
+    let q2 = -q1.frobenius_map();
+    f = f * &line_function(&t, &q1, p);
+    t = t + &q1;
+    f * &line_function(&t, &q2, p)
+}
+
+/// Raises the result `f` of the Miller loop to the power of `(p^12 - 1) / r`, which maps it
+/// to a unique representative of the `r`-th roots of unity.
+///
+/// ## Panics
+///
+/// Panics if `f` is zero, which is never the result of a Miller loop.
+pub fn final_exponentiation<C: PairingCurve>(f: &Fp12<C>) -> Fp12<C> {
+    // (p^12 - 1) / r = (p^6 - 1) * (p^2 + 1) * (p^4 - p^2 + 1) / r, where the first two factors
+    // are cheap to compute with the Frobenius map. Note that x^(p^6) is the conjugate of x.
+    let f = f.conjugate() * &f.inverse().expect("f must not be zero");
+    let f = f.frobenius_map().frobenius_map() * &f;
+
+    let p_squared = C::field_modulus().pow(2);
+    f.pow(&((&p_squared * &p_squared - &p_squared + 1) / C::order()))
+}
+
+/// Evaluates the line through `t` and `q` at `p`, which is the tangent if `t` equals `q`.
+///
+/// Lines on the twist are mapped to lines on the curve over `Fp12` with the untwisting isomorphism,
+/// which is `(x, y) -> (x * w^2, y * w^3)` for a D-type twist and `(x, y) -> (x / w^2, y / w^3)`
+/// for an M-type twist. For a line `y = λ(x - x_T) + y_T` on the twist, this results in
+/// `y_P - λ x_P w + (λ x_T - y_T) w^3` for a D-type twist and, after multiplying with `w^3`, in
+/// `λ x_T - y_T - λ x_P w^2 + y_P w^3` for an M-type twist, where `w^2 = v` and `w^3 = v * w`.
+fn line_function<C: PairingCurve>(
+    t: &G2Point<C>,
+    q: &G2Point<C>,
+    (x_p, y_p): (&FieldElement<C>, &FieldElement<C>),
+) -> Fp12<C> {
+    let (Some((x_t, y_t)), Some((x_q, y_q))) = (t.as_coordinates(), q.as_coordinates()) else {
+        return Fp12::one();
+    };
+
+    let lambda = if x_t != x_q {
+        // SAFETY: The inverse exists since x_t != x_q.
+        (y_q - y_t) * &(x_q - x_t).inverse().unwrap()
+    } else if y_t == y_q && !y_t.is_zero() {
+        let x_squared = x_t.square();
+        // SAFETY: The inverse exists since y_t is not zero.
+        (&x_squared + &x_squared + &x_squared) * &(y_t + y_t).inverse().unwrap()
+    } else {
+        // The line is vertical, its value lies in Fp6 and is eliminated by the final
+        // exponentiation.
+        return Fp12::one();
+    };
+
+    match C::twist_type() {
+        TwistType::D => Fp12::new(
+            Fp6::from(Fp2::from(y_p.clone())),
+            Fp6::new(-lambda.scale(x_p), &lambda * x_t - y_t, Fp2::zero()),
+        ),
+        // The factor w^3 is eliminated by the final exponentiation as well, since its square
+        // lies in Fp2.
+        TwistType::M => Fp12::new(
+            Fp6::new(&lambda * x_t - y_t, -lambda.scale(x_p), Fp2::zero()),
+            Fp6::new(Fp2::zero(), Fp2::from(y_p.clone()), Fp2::zero()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        curves::{Bls12_381, Bn128},
+        test_utils::hex_int,
+        Scalar,
+    };
+
+    use super::*;
+
+    /// Returns the coefficients of the element in py_ecc's representation of `Fp12` as
+    /// `Fp[w] / (w^12 - 18 w^6 + 82)`.
+    ///
+    /// Both representations use the same `w` with `w^6 = ξ = 9 + u`, so the coefficient
+    /// `c0 + c1 u` of `w^k` in the tower becomes `(c0 - 9 c1) w^k + c1 w^(k + 6)`.
+    fn to_py_ecc_coefficients(element: &Fp12<Bn128>) -> Vec<BigInt> {
+        let by_power_of_w = [
+            element.c0().c0(),
+            element.c1().c0(),
+            element.c0().c1(),
+            element.c1().c1(),
+            element.c0().c2(),
+            element.c1().c2(),
+        ];
+
+        let mut coefficients = vec![BigInt::ZERO; 12];
+        for (k, value) in by_power_of_w.into_iter().enumerate() {
+            coefficients[k] = (value.c0() - &(value.c1() * &FieldElement::new(9))).value();
+            coefficients[k + 6] = value.c1().value();
+        }
+        coefficients
+    }
+
+    #[test]
+    fn pairing_of_generators() {
+        let result = pairing(&Bn128::generator(), &G2Point::generator());
+
+        // Expected result computed with the bn128 reference implementation of py_ecc.
+        let expected = [
+            "28c6e04df059260df7d2d2a1f9b5f77676d1939847852c4ed50d2318744c1d5f",
+            "17bb74adab1705c26133af1dac87044a3833ac011018e8158da48382bbd2dcd6",
+            "0d3bd72f54d742f78ea9e6015c8ea2f2e7fbb728c9c905ec531dcf7de5b246f0",
+            "090cb8ee97e091a667af03882b06c3ecb4e437993cbd1b05b98c7f9dfcfe9c40",
+            "016b6d855b5cbf76f9829a309db52f5c442f65ae29f996af59d65f85f4afe78a",
+            "0a0272204db51dadc0342bd318b9302a44faec12ff500bdd4d4b012ffe45f36f",
+            "084f330485b09e866bc2f2ea2b897394deaf3f12aa31f28cb0552990967d4704",
+            "27ed208e7a0b55ae6e710bbfbd2fd922669c026360e37cc5b2ab862411536104",
+            "2067586885c3318eeffa1938c754fe3c60224ee5ae15e66af6b5104c47c8c5d8",
+            "279db296f9d479292532c7c493d8e0722b6efae42158387564889c79fc038ee3",
+            "2b03614464f04dd772d86df88674c270ffc8747ea13e72da95e3594468f222c4",
+            "108c19d15f9446f744d0f110405d3856d6cc3bda6c4d537663729f5257628417",
+        ]
+        .map(hex_int);
+        assert_eq!(to_py_ecc_coefficients(&result), expected);
+
+        // The result is an r-th root of unity other than one.
+        assert_ne!(result, Fp12::one());
+        assert_eq!(result.pow(&Bn128::order()), Fp12::one());
+    }
+
+    #[test]
+    fn pairing_of_generators_bls12_381() {
+        let result = pairing(&Bls12_381::generator(), &G2Point::generator());
+
+        // Expected result computed with RELIC, as listed in the tests of the ZCash bls12_381 crate.
+        // Both compute the final exponentiation with an addition chain in x, which raises to the
+        // power of 3 (p^4 - p^2 + 1) / r instead, so their result is the cube of ours.
+        let expected = [
+            "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+            "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+            "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+            "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+            "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+            "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+            "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+            "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+            "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+            "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+            "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+            "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+        ]
+        .map(hex_int);
+        let cube = result.pow(&BigInt::from(3));
+        let coefficients = [cube.c0(), cube.c1()]
+            .into_iter()
+            .flat_map(|c| [c.c0(), c.c1(), c.c2()])
+            .flat_map(|c| [c.c0().value(), c.c1().value()])
+            .collect::<Vec<_>>();
+        assert_eq!(coefficients, expected);
+
+        assert_eq!(result.pow(&Bls12_381::order()), Fp12::one());
+    }
+
+    #[test]
+    fn bilinearity() {
+        fn check<C: PairingCurve>() {
+            let p = C::generator();
+            let q = G2Point::<C>::generator();
+            let a = Scalar::new(0x1234_5678_9abc_u64);
+            let b = Scalar::new(0xfedc_ba98_7654_u64);
+
+            let expected = pairing(&p, &q).pow((&a * &b).value());
+            assert_eq!(pairing(&(&p * &a), &(&q * &b)), expected);
+            assert_eq!(pairing(&(&p * &(&a * &b)), &q), expected);
+            assert_eq!(pairing(&p, &(&q * &(&a * &b))), expected);
+        }
+
+        check::<Bn128>();
+        check::<Bls12_381>();
+    }
+
+    #[test]
+    fn pairing_with_point_at_infinity() {
+        assert_eq!(
+            pairing(
+                &CurvePoint::point_at_infinity(),
+                &G2Point::<Bn128>::generator()
+            ),
+            Fp12::one()
+        );
+        assert_eq!(
+            pairing(&Bn128::generator(), &G2Point::point_at_infinity()),
+            Fp12::one()
+        );
+    }
+
+    #[test]
+    fn multi_pairing_product_checks() {
+        let p = Bn128::generator();
+        let q = G2Point::<Bn128>::generator();
+        let a = Scalar::new(42);
+        let b = Scalar::new(1337);
+
+        // e(aP, bQ) * e(-abP, Q) = 1
+        let pairs = [(&p * &a, &q * &b), (-(&p * &(&a * &b)), q.clone())];
+        assert!(pairing_check(&pairs));
+        assert_eq!(
+            multi_pairing(&pairs[..1]),
+            pairing(&pairs[0].0, &pairs[0].1)
+        );
+
+        let pairs = [(&p * &a, &q * &b), (-(&p * &a), q.clone())];
+        assert!(!pairing_check(&pairs));
+
+        assert!(pairing_check::<Bn128>(&[]));
+    }
+}
@@ -0,0 +1,334 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use num::{bigint::Sign, BigInt};
+
+use crate::{
+    jacobian::JacobianPoint, projective::ProjectivePoint, wnaf::wnaf, CurvePoint, FieldElement,
+    FieldRef, Point, PointError, PrimeFieldElement, WeierstrassCurve,
+};
+
+/// The field and the parameters of a curve `y^2 = x^3 + ax + b`, which the point arithmetic in
+/// this module, [`JacobianPoint`] and [`ProjectivePoint`] are generic over.
+///
+/// A curve implementing [`WeierstrassCurve`] is represented by the zero-sized [`StaticCurve`],
+/// a [`DynamicCurve`](crate::DynamicCurve) by a reference to it. This way, [`CurvePoint`] and
+/// [`DynamicPoint`](crate::DynamicPoint) use the same formulas and only differ in where the
+/// parameters are looked up.
+pub(crate) trait CurveContext: Copy + Debug {
+    /// The elements of the field over which the curve is defined.
+    type Element: PrimeFieldElement;
+    /// The affine points on the curve.
+    type Point: Clone;
+
+    /// Returns the field element with the given `value`, reduced modulo the field modulus.
+    fn element(self, value: BigInt) -> Self::Element;
+    /// Returns the additive identity of the field.
+    fn zero(self) -> Self::Element;
+    /// Returns the multiplicative identity of the field.
+    fn one(self) -> Self::Element;
+    /// Returns the parameter `a` of the curve.
+    fn a(self) -> Self::Element;
+    /// Returns the parameter `b` of the curve.
+    fn b(self) -> Self::Element;
+    /// Returns the field modulus of the curve.
+    fn field_modulus(self) -> BigInt;
+    /// Returns the order of the curve's generator.
+    fn order(self) -> BigInt;
+    /// Returns the affine point with the given coordinates or the point at infinity for `None`.
+    fn point(self, coordinates: Option<(Self::Element, Self::Element)>) -> Self::Point;
+    /// Returns the coordinates of `point` or `None` if it's the point at infinity.
+    fn coordinates(point: &Self::Point) -> Option<(&Self::Element, &Self::Element)>;
+}
+
+/// The [`CurveContext`] of the curve `C`, whose parameters are known at compile time.
+pub(crate) struct StaticCurve<C: WeierstrassCurve>(PhantomData<C>);
+
+impl<C: WeierstrassCurve> StaticCurve<C> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: WeierstrassCurve> Clone for StaticCurve<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: WeierstrassCurve> Copy for StaticCurve<C> {}
+
+impl<C: WeierstrassCurve> Debug for StaticCurve<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StaticCurve")
+    }
+}
+
+impl<C: WeierstrassCurve> CurveContext for StaticCurve<C> {
+    type Element = FieldElement<C>;
+    type Point = CurvePoint<C>;
+
+    fn element(self, value: BigInt) -> FieldElement<C> {
+        FieldElement::new(value)
+    }
+
+    fn zero(self) -> FieldElement<C> {
+        FieldElement::zero()
+    }
+
+    fn one(self) -> FieldElement<C> {
+        FieldElement::one()
+    }
+
+    fn a(self) -> FieldElement<C> {
+        FieldElement::new(C::a())
+    }
+
+    fn b(self) -> FieldElement<C> {
+        FieldElement::new(C::b())
+    }
+
+    fn field_modulus(self) -> BigInt {
+        C::field_modulus()
+    }
+
+    fn order(self) -> BigInt {
+        C::order()
+    }
+
+    fn point(self, coordinates: Option<(FieldElement<C>, FieldElement<C>)>) -> CurvePoint<C> {
+        match coordinates {
+            Some((x, y)) => Point::Point { x, y }.into(),
+            None => CurvePoint::point_at_infinity(),
+        }
+    }
+
+    fn coordinates(point: &CurvePoint<C>) -> Option<(&FieldElement<C>, &FieldElement<C>)> {
+        point.as_coordinates()
+    }
+}
+
+/// Creates the point with the given coordinates, see [`CurvePoint::try_new`].
+pub(crate) fn try_new<K: CurveContext>(
+    curve: K,
+    x: BigInt,
+    y: BigInt,
+) -> Result<K::Point, PointError> {
+    let field_modulus = curve.field_modulus();
+
+    for coordinate in [&x, &y] {
+        if coordinate < &BigInt::ZERO || coordinate >= &field_modulus {
+            return Err(PointError::CoordinateOutOfRange);
+        }
+    }
+
+    let point = curve.point(Some((curve.element(x), curve.element(y))));
+    if !is_on_curve(curve, &point) {
+        return Err(PointError::NotOnCurve);
+    }
+
+    Ok(point)
+}
+
+/// Encodes the point as defined in Section 2.3.3 of SEC 1, see [`CurvePoint::to_sec1`].
+pub(crate) fn to_sec1<K: CurveContext>(point: &K::Point, compressed: bool) -> Vec<u8> {
+    let Some((x, y)) = K::coordinates(point) else {
+        return vec![0x00];
+    };
+
+    let x = x.to_bytes_be();
+    let mut bytes = Vec::with_capacity(1 + 2 * x.len());
+    if compressed {
+        bytes.push(if y.value().bit(0) { 0x03 } else { 0x02 });
+        bytes.extend(x);
+    } else {
+        bytes.push(0x04);
+        bytes.extend(x);
+        bytes.extend(y.to_bytes_be());
+    }
+
+    bytes
+}
+
+/// Decodes a point from its SEC 1 encoding, see [`CurvePoint::from_sec1`].
+pub(crate) fn from_sec1<K: CurveContext>(curve: K, bytes: &[u8]) -> Result<K::Point, PointError> {
+    let coordinate_len = curve.field_modulus().bits().div_ceil(8) as usize;
+    let coordinate = |bytes: &[u8]| BigInt::from_bytes_be(Sign::Plus, bytes);
+
+    match bytes {
+        [0x00] => Ok(curve.point(None)),
+        [0x04, coordinates @ ..] if coordinates.len() == 2 * coordinate_len => {
+            let (x, y) = coordinates.split_at(coordinate_len);
+            try_new(curve, coordinate(x), coordinate(y))
+        }
+        [prefix @ (0x02 | 0x03), x @ ..] if x.len() == coordinate_len => {
+            let x = coordinate(x);
+            if x >= curve.field_modulus() {
+                return Err(PointError::CoordinateOutOfRange);
+            }
+
+            let x = curve.element(x);
+            let y = curve_equation_rhs(curve, &x)
+                .sqrt()
+                .ok_or(PointError::NotOnCurve)?;
+
+            // Pick the root whose parity matches the prefix. If y is zero, both roots
+            // are even and an odd prefix is invalid.
+            let is_odd = *prefix == 0x03;
+            let y = if y.value().bit(0) == is_odd { y } else { -y };
+            if y.value().bit(0) != is_odd {
+                return Err(PointError::InvalidEncoding);
+            }
+
+            Ok(curve.point(Some((x, y))))
+        }
+        _ => Err(PointError::InvalidEncoding),
+    }
+}
+
+/// Returns `true` if the point satisfies the curve equation `y^2 = x^3 + ax + b`.
+///
+/// The point at infinity is always on the curve.
+pub(crate) fn is_on_curve<K: CurveContext>(curve: K, point: &K::Point) -> bool {
+    let Some((x, y)) = K::coordinates(point) else {
+        return true;
+    };
+
+    y.square() == curve_equation_rhs(curve, x)
+}
+
+/// Returns the right-hand side `x^3 + ax + b` of the curve equation.
+fn curve_equation_rhs<K: CurveContext>(curve: K, x: &K::Element) -> K::Element {
+    x.square() * x + &(curve.a() * x) + &curve.b()
+}
+
+/// Adds `q` to `p` on the elliptic curve.
+///
+/// Formulas taken from https://en.wikipedia.org/wiki/Elliptic_curve_point_multiplication.
+pub(crate) fn add<K: CurveContext>(curve: K, p: &K::Point, q: &K::Point) -> K::Point
+where
+    for<'a> &'a K::Element: FieldRef<K::Element>,
+{
+    match (K::coordinates(p), K::coordinates(q)) {
+        (None, _) => q.clone(),
+        (_, None) => p.clone(),
+        (Some((x_p, y_p)), Some((x_q, y_q))) => {
+            if x_p == x_q && y_p == y_q {
+                // Doubling a point with y = 0 results in the point at infinity,
+                // since the tangent at such a point is vertical.
+                let Some(two_y_inverse) = (y_p + y_p).inverse() else {
+                    return curve.point(None);
+                };
+
+                let lambda =
+                    (curve.element(3.into()) * &x_p.square() + &curve.a()) * &two_y_inverse;
+
+                let x_r = lambda.square() - x_p - x_p;
+                let y_r = lambda * &(x_p - &x_r) - y_p;
+
+                curve.point(Some((x_r, y_r)))
+            } else if x_p == x_q {
+                // If the x-coordinates match, there will be no intersection with a third point,
+                // so we return the point at infinity.
+                curve.point(None)
+            } else {
+                // SAFETY: The inverse exists since x_p != x_q.
+                let lambda = (y_q - y_p) * &(x_q - x_p).inverse().unwrap();
+
+                let x_r = lambda.square() - x_p - x_q;
+                let y_r = lambda * &(x_p - &x_r) - y_p;
+
+                curve.point(Some((x_r, y_r)))
+            }
+        }
+    }
+}
+
+/// Returns the inverse of `point`, see [`CurvePoint::negate`].
+pub(crate) fn negate<K: CurveContext>(curve: K, point: &K::Point) -> K::Point {
+    // The inverse of a point is its reflection across the x-axis, i.e. it has the same
+    // x-coordinate and the additive inverse of y. The inverse of the point at infinity is itself.
+    curve.point(K::coordinates(point).map(|(x, y)| (x.clone(), -y.clone())))
+}
+
+/// Multiplies `scalar` with `point` using double-and-add in Jacobian coordinates.
+///
+/// Negative scalars are handled by multiplying the negated point with the absolute value.
+pub(crate) fn multiply<K: CurveContext>(curve: K, point: &K::Point, scalar: &BigInt) -> K::Point
+where
+    for<'a> &'a K::Element: FieldRef<K::Element>,
+{
+    if scalar < &BigInt::ZERO {
+        return multiply(curve, &negate(curve, point), &-scalar);
+    }
+
+    // Left-to-right double-and-add.
+    let mut result = JacobianPoint::point_at_infinity(curve);
+    for bit in (0..scalar.bits()).rev() {
+        result = result.double();
+        if scalar.bit(bit) {
+            result = result.add_mixed(point);
+        }
+    }
+
+    result.to_affine()
+}
+
+/// Multiplies `point` with the non-negative `scalar` using its width-`window` non-adjacent form,
+/// see [`CurvePoint::mul_wnaf`].
+pub(crate) fn mul_wnaf<K: CurveContext>(
+    curve: K,
+    point: &K::Point,
+    scalar: &BigInt,
+    window: usize,
+) -> K::Point
+where
+    for<'a> &'a K::Element: FieldRef<K::Element>,
+{
+    let digits = wnaf(scalar, window);
+
+    // The ith entry in the table is (2i + 1) * point. The table is converted to affine
+    // coordinates with a single inversion, so that the cheaper mixed addition can be used.
+    let point = JacobianPoint::from_affine(curve, point);
+    let point_doubled = point.double();
+    let mut table = vec![point];
+    for i in 1..1 << (window - 2) {
+        table.push(table[i - 1].add(&point_doubled));
+    }
+    let table = JacobianPoint::batch_to_affine(&table);
+
+    let mut result = JacobianPoint::point_at_infinity(curve);
+    for digit in digits.into_iter().rev() {
+        result = result.double();
+
+        let index = (digit.unsigned_abs() / 2) as usize;
+        if digit > 0 {
+            result = result.add_mixed(&table[index]);
+        } else if digit < 0 {
+            result = result.add_mixed(&negate(curve, &table[index]));
+        }
+    }
+
+    result.to_affine()
+}
+
+/// Multiplies `point` with the `scalar` in the range `[0, order)` using a Montgomery ladder,
+/// see [`CurvePoint::mul_ct`].
+pub(crate) fn mul_ct<K: CurveContext>(curve: K, point: &K::Point, scalar: &BigInt) -> K::Point
+where
+    for<'a> &'a K::Element: FieldRef<K::Element>,
+{
+    let mut r0 = ProjectivePoint::point_at_infinity(curve);
+    let mut r1 = ProjectivePoint::from_affine(curve, point);
+
+    for bit in (0..curve.order().bits()).rev() {
+        let choice = scalar.bit(bit);
+
+        // Invariant: r1 = r0 + point.
+        r0.conditional_swap(&mut r1, choice);
+        r1 = r0.add(&r1);
+        r0 = r0.add(&r0);
+        r0.conditional_swap(&mut r1, choice);
+    }
+
+    r0.to_affine()
+}
@@ -0,0 +1,287 @@
+//! The `ecAdd` (`0x06`), `ecMul` (`0x07`) and `ecPairing` (`0x08`) precompiles of the EVM on
+//! [`Bn128`], as specified in [EIP-196](https://eips.ethereum.org/EIPS/eip-196) and
+//! [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+//!
+//! All functions take the raw call data of the precompile and return its exact output.
+//! Field elements and scalars are encoded as 32-byte big-endian integers and the point at
+//! infinity is encoded with all coordinates set to zero. Gas accounting is left to the caller.
+
+use crate::{
+    curves::Bn128, pairing_check, CurvePoint, FieldElement, Fp2, G2Point, PointError,
+    PrecompileError, Scalar,
+};
+
+/// The length of an `ecAdd` input, i.e. two encoded points.
+const ADD_INPUT_LEN: usize = 128;
+/// The length of an `ecMul` input, i.e. an encoded point and a scalar.
+const MUL_INPUT_LEN: usize = 96;
+/// The length of a single `(G1, G2)` pair of an `ecPairing` input.
+const PAIRING_ELEMENT_LEN: usize = 192;
+
+/// Executes the `ecAdd` precompile, which adds two points of `G1`.
+///
+/// Shorter inputs are padded with zeros and bytes beyond the first 128 are ignored.
+/// Returns an error if a coordinate is not smaller than the field modulus or a point is not
+/// on the curve.
+pub fn ec_add(input: &[u8]) -> Result<[u8; 64], PrecompileError> {
+    let input = right_pad::<ADD_INPUT_LEN>(input);
+
+    let p = read_g1_point(&input[..64])?;
+    let q = read_g1_point(&input[64..])?;
+
+    Ok(write_g1_point(&(p + &q)))
+}
+
+/// Executes the `ecMul` precompile, which multiplies a point of `G1` with a scalar.
+///
+/// Shorter inputs are padded with zeros and bytes beyond the first 96 are ignored.
+/// The scalar may be any 256-bit integer. Returns an error if a coordinate is not smaller than
+/// the field modulus or the point is not on the curve.
+pub fn ec_mul(input: &[u8]) -> Result<[u8; 64], PrecompileError> {
+    let input = right_pad::<MUL_INPUT_LEN>(input);
+
+    let p = read_g1_point(&input[..64])?;
+    // Reducing the scalar does not change the result, since the curve has prime order.
+    let scalar = Scalar::from_bytes_be_reduced(&input[64..]);
+
+    Ok(write_g1_point(&(p * &scalar)))
+}
+
+/// Executes the `ecPairing` precompile, which checks whether the product of the pairings
+/// `e(P_1, Q_1) * ... * e(P_k, Q_k)` is one.
+///
+/// The input consists of `k` pairs of a `G1` point `(x, y)` and a `G2` point
+/// `(x_im, x_re, y_im, y_re)`, where each `G2` coordinate is encoded with its imaginary part
+/// first. The output is `1` if the check holds and `0` otherwise, encoded as a 32-byte integer.
+/// The empty input results in `1`.
+///
+/// Returns an error if the input length is not a multiple of 192 bytes, a coordinate is not
+/// smaller than the field modulus, a point is not on its curve or a `G2` point is not in the
+/// subgroup of order `r`.
+pub fn ec_pairing(input: &[u8]) -> Result<[u8; 32], PrecompileError> {
+    if !input.len().is_multiple_of(PAIRING_ELEMENT_LEN) {
+        return Err(PrecompileError::InvalidInputLength);
+    }
+
+    let pairs = input
+        .chunks_exact(PAIRING_ELEMENT_LEN)
+        .map(|element| {
+            Ok((
+                read_g1_point(&element[..64])?,
+                read_g2_point(&element[64..])?,
+            ))
+        })
+        .collect::<Result<Vec<_>, PrecompileError>>()?;
+
+    let mut output = [0; 32];
+    output[31] = u8::from(pairing_check(&pairs));
+    Ok(output)
+}
+
+/// Copies `input` into an array of length `N`, padding it with zeros or truncating it.
+fn right_pad<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut padded = [0; N];
+    let len = input.len().min(N);
+    padded[..len].copy_from_slice(&input[..len]);
+    padded
+}
+
+/// Reads a point `(x, y)` of `G1` from 64 bytes.
+fn read_g1_point(bytes: &[u8]) -> Result<CurvePoint<Bn128>, PointError> {
+    let x = FieldElement::<Bn128>::from_bytes_be(&bytes[..32])?;
+    let y = FieldElement::<Bn128>::from_bytes_be(&bytes[32..64])?;
+
+    // (0, 0) is not on the curve, so it can represent the point at infinity.
+    if x.is_zero() && y.is_zero() {
+        return Ok(CurvePoint::point_at_infinity());
+    }
+
+    CurvePoint::try_new(x.value(), y.value())
+}
+
+/// Reads a point `(x_im, x_re, y_im, y_re)` of `G2` from 128 bytes.
+fn read_g2_point(bytes: &[u8]) -> Result<G2Point<Bn128>, PointError> {
+    let [x_im, x_re, y_im, y_re] =
+        [0, 1, 2, 3].map(|i| FieldElement::from_bytes_be(&bytes[32 * i..32 * (i + 1)]));
+    let x = Fp2::new(x_re?, x_im?);
+    let y = Fp2::new(y_re?, y_im?);
+
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Point::point_at_infinity());
+    }
+
+    let point = G2Point::try_new(x, y)?;
+    if !point.is_in_subgroup() {
+        return Err(PointError::NotInSubgroup);
+    }
+
+    Ok(point)
+}
+
+/// Encodes a point of `G1` as `x || y`, where the point at infinity is encoded as all zeros.
+fn write_g1_point(point: &CurvePoint<Bn128>) -> [u8; 64] {
+    let mut output = [0; 64];
+    if let Some((x, y)) = point.as_coordinates() {
+        output[..32].copy_from_slice(&x.to_bytes_be());
+        output[32..].copy_from_slice(&y.to_bytes_be());
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::{test_utils::hex, WeierstrassCurve};
+
+    use super::*;
+
+    /// Runs the official Ethereum precompile test vectors, as used by go-ethereum.
+    fn check_test_vectors<const N: usize>(
+        vectors: &str,
+        precompile: fn(&[u8]) -> Result<[u8; N], PrecompileError>,
+    ) {
+        let vectors: serde_json::Value = serde_json::from_str(vectors).unwrap();
+        for vector in vectors.as_array().unwrap() {
+            let name = vector["Name"].as_str().unwrap();
+            let input = hex(vector["Input"].as_str().unwrap());
+            let expected = hex(vector["Expected"].as_str().unwrap());
+
+            assert_eq!(precompile(&input).unwrap().to_vec(), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn ec_add_test_vectors() {
+        check_test_vectors(include_str!("../tests/fixtures/bn256Add.json"), ec_add);
+    }
+
+    #[test]
+    fn ec_mul_test_vectors() {
+        check_test_vectors(
+            include_str!("../tests/fixtures/bn256ScalarMul.json"),
+            ec_mul,
+        );
+    }
+
+    #[test]
+    fn ec_pairing_test_vectors() {
+        check_test_vectors(
+            include_str!("../tests/fixtures/bn256Pairing.json"),
+            ec_pairing,
+        );
+    }
+
+    #[test]
+    fn input_padding() {
+        let generator = write_g1_point(&Bn128::generator());
+        let doubled = write_g1_point(&(Bn128::generator() + &Bn128::generator()));
+
+        // G + 0, where the second point is omitted entirely.
+        assert_eq!(ec_add(&generator), Ok(generator));
+        // The scalar's single byte 0x02 is padded with 31 zeros, i.e. it is 2 << 248.
+        let mut input = generator.to_vec();
+        input.push(0x02);
+        assert_eq!(
+            ec_mul(&input),
+            Ok(write_g1_point(
+                &(Bn128::generator() * &Scalar::new(BigInt::from(2) << 248))
+            ))
+        );
+        // Excess bytes are ignored.
+        let mut input = [generator, generator].concat();
+        input.extend([0xff; 10]);
+        assert_eq!(ec_add(&input), Ok(doubled));
+    }
+
+    /// Inputs that are cut off inside a coordinate are padded with zeros as well, which results
+    /// in points that are not on the curve. The points are those of the `cdetrio9` vector.
+    #[test]
+    fn ec_add_truncated_inputs() {
+        let p = concat!(
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9",
+            "01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c",
+        );
+        let q_x = "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869";
+
+        // Truncating Q after its x-coordinate pads y with zeros.
+        assert_eq!(
+            ec_add(&hex(&[p, q_x].concat())),
+            Err(PrecompileError::InvalidPoint(PointError::NotOnCurve))
+        );
+        // The same holds for a P that is cut off in the middle of its y-coordinate.
+        assert_eq!(
+            ec_add(&hex(&p[..96])),
+            Err(PrecompileError::InvalidPoint(PointError::NotOnCurve))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_g1_points() {
+        let mut not_on_curve = [0; 128];
+        not_on_curve[31] = 1;
+        not_on_curve[63] = 1;
+        assert_eq!(
+            ec_add(&not_on_curve),
+            Err(PrecompileError::InvalidPoint(PointError::NotOnCurve))
+        );
+        assert_eq!(
+            ec_mul(&not_on_curve),
+            Err(PrecompileError::InvalidPoint(PointError::NotOnCurve))
+        );
+
+        // The x-coordinate of the generator plus the field modulus.
+        let mut out_of_range = [0; 96];
+        let (_, x) = (Bn128::field_modulus() + BigInt::from(1)).to_bytes_be();
+        out_of_range[..32].copy_from_slice(&x);
+        out_of_range[63] = 2;
+        assert_eq!(
+            ec_mul(&out_of_range),
+            Err(PrecompileError::InvalidPoint(
+                PointError::CoordinateOutOfRange
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_pairing_inputs() {
+        assert_eq!(
+            ec_pairing(&[0; 193]),
+            Err(PrecompileError::InvalidInputLength)
+        );
+        assert_eq!(
+            ec_pairing(&[0; 191]),
+            Err(PrecompileError::InvalidInputLength)
+        );
+
+        // A point on the twist outside of G2 with x = 1, paired with the point at infinity.
+        let mut input = [0; 192];
+        input[64 + 63] = 1;
+        input[128..].copy_from_slice(&hex(concat!(
+            "0d1271953ed9ea0836846e70a1934187998c7f790cb4d7511b7f8da82de048a4",
+            "2869111d5381f072f8e2728fdb825a51aadd70e52c9830e9ab4b871c0531f1bb",
+        )));
+        assert_eq!(
+            ec_pairing(&input),
+            Err(PrecompileError::InvalidPoint(PointError::NotInSubgroup))
+        );
+
+        // Flipping the lowest bit of the real part of y moves the point off the twist.
+        input[191] ^= 1;
+        assert_eq!(
+            ec_pairing(&input),
+            Err(PrecompileError::InvalidPoint(PointError::NotOnCurve))
+        );
+
+        // The field modulus as a G2 coordinate.
+        let mut input = [0; 192];
+        let (_, modulus) = Bn128::field_modulus().to_bytes_be();
+        input[64..96].copy_from_slice(&modulus);
+        assert_eq!(
+            ec_pairing(&input),
+            Err(PrecompileError::InvalidPoint(
+                PointError::CoordinateOutOfRange
+            ))
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{
+    point_arithmetic::{CurveContext, StaticCurve},
+    CurvePoint, FieldRef, PrimeFieldElement, WeierstrassCurve,
+};
+
+/// A point in homogeneous projective coordinates `(X : Y : Z)`, which represents the affine point
+/// `(X / Z, Y / Z)`. The point at infinity is represented as `(0 : 1 : 0)`.
+///
+/// Points in this representation are added with the complete formulas from
+/// Renes, Costello and Batina, "Complete addition formulas for prime order elliptic curves"
+/// (https://eprint.iacr.org/2015/1060). The formulas have no exceptional cases, i.e. the same
+/// sequence of field operations is executed for doublings, additions of a point and its negation
+/// and additions involving the point at infinity.
+///
+/// The formulas are only complete on curves of prime order, i.e. curves without points of order two.
+#[derive(Debug)]
+pub(crate) struct ProjectivePoint<K: CurveContext> {
+    curve: K,
+    x: K::Element,
+    y: K::Element,
+    z: K::Element,
+}
+
+impl<K: CurveContext> Clone for ProjectivePoint<K> {
+    fn clone(&self) -> Self {
+        Self {
+            curve: self.curve,
+            x: self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+}
+
+impl<C: WeierstrassCurve> From<&CurvePoint<C>> for ProjectivePoint<StaticCurve<C>> {
+    fn from(point: &CurvePoint<C>) -> Self {
+        Self::from_affine(StaticCurve::new(), point)
+    }
+}
+
+impl<K: CurveContext> ProjectivePoint<K>
+where
+    for<'a> &'a K::Element: FieldRef<K::Element>,
+{
+    /// Converts the affine `point` on `curve` into projective coordinates.
+    pub(crate) fn from_affine(curve: K, point: &K::Point) -> Self {
+        match K::coordinates(point) {
+            None => Self::point_at_infinity(curve),
+            Some((x, y)) => Self {
+                curve,
+                x: x.clone(),
+                y: y.clone(),
+                z: curve.one(),
+            },
+        }
+    }
+
+    /// Returns the point at infinity, represented as `(0 : 1 : 0)`.
+    pub(crate) fn point_at_infinity(curve: K) -> Self {
+        Self {
+            curve,
+            x: curve.zero(),
+            y: curve.one(),
+            z: curve.zero(),
+        }
+    }
+
+    /// Converts the point back to affine coordinates using a single field inversion.
+    pub(crate) fn to_affine(&self) -> K::Point {
+        let Some(z_inverse) = self.z.inverse() else {
+            return self.curve.point(None);
+        };
+
+        self.curve
+            .point(Some((&self.x * &z_inverse, &self.y * &z_inverse)))
+    }
+
+    /// Swaps `self` and `other` if `choice` is `true` without branching on `choice`.
+    ///
+    /// The swap is computed arithmetically as `t = c * (a - b), a = a - t, b = b + t`
+    /// where `c` is either zero or one, so the same field operations are executed for both choices.
+    pub(crate) fn conditional_swap(&mut self, other: &mut Self, choice: bool) {
+        let choice = self.curve.element(u8::from(choice).into());
+
+        for (a, b) in [
+            (&mut self.x, &mut other.x),
+            (&mut self.y, &mut other.y),
+            (&mut self.z, &mut other.z),
+        ] {
+            let t = &choice * &(&*a - b);
+            *a = &*a - &t;
+            *b = &*b + &t;
+        }
+    }
+
+    /// Adds `other` to `self` using the complete formulas,
+    /// picking the specialized variant if the curve parameter `a` is zero.
+    pub(crate) fn add(&self, other: &ProjectivePoint<K>) -> Self {
+        let a = self.curve.a();
+        let b = self.curve.b();
+        let b3 = &b + &b + &b;
+
+        if a.is_zero() {
+            self.add_a_zero(other, &b3)
+        } else {
+            self.add_general(other, &a, &b3)
+        }
+    }
+
+    /// Algorithm 1 from the paper for arbitrary `a`.
+    fn add_general(&self, other: &ProjectivePoint<K>, a: &K::Element, b3: &K::Element) -> Self {
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * &(x2 + y2);
+        let t4 = &t0 + &t1;
+        let t3 = t3 - &t4;
+        let t4 = (x1 + z1) * &(x2 + z2);
+        let t5 = &t0 + &t2;
+        let t4 = t4 - &t5;
+        let t5 = (y1 + z1) * &(y2 + z2);
+        let x3 = &t1 + &t2;
+        let t5 = t5 - &x3;
+        let z3 = a * &t4;
+        let x3 = b3 * &t2;
+        let z3 = x3 + &z3;
+        let x3 = &t1 - &z3;
+        let z3 = t1 + &z3;
+        let y3 = &x3 * &z3;
+        let t1 = &t0 + &t0;
+        let t1 = t1 + &t0;
+        let t2 = a * &t2;
+        let t4 = b3 * &t4;
+        let t1 = t1 + &t2;
+        let t2 = &t0 - &t2;
+        let t2 = a * &t2;
+        let t4 = t4 + &t2;
+        let t0 = &t1 * &t4;
+        let y3 = y3 + &t0;
+        let t0 = &t5 * &t4;
+        let x3 = &t3 * &x3;
+        let x3 = x3 - &t0;
+        let t0 = t3 * &t1;
+        let z3 = t5 * &z3;
+        let z3 = z3 + &t0;
+
+        Self {
+            curve: self.curve,
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Algorithm 7 from the paper for `a = 0`.
+    fn add_a_zero(&self, other: &ProjectivePoint<K>, b3: &K::Element) -> Self {
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * &(x2 + y2);
+        let t4 = &t0 + &t1;
+        let t3 = t3 - &t4;
+        let t4 = (y1 + z1) * &(y2 + z2);
+        let x3 = &t1 + &t2;
+        let t4 = t4 - &x3;
+        let x3 = (x1 + z1) * &(x2 + z2);
+        let y3 = &t0 + &t2;
+        let y3 = x3 - &y3;
+        let x3 = &t0 + &t0;
+        let t0 = x3 + &t0;
+        let t2 = b3 * &t2;
+        let z3 = &t1 + &t2;
+        let t1 = t1 - &t2;
+        let y3 = b3 * &y3;
+        let x3 = &t4 * &y3;
+        let t2 = &t3 * &t1;
+        let x3 = t2 - &x3;
+        let y3 = y3 * &t0;
+        let t1 = t1 * &z3;
+        let y3 = t1 + &y3;
+        let t0 = t0 * &t3;
+        let z3 = z3 * &t4;
+        let z3 = z3 + &t0;
+
+        Self {
+            curve: self.curve,
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        curves::{Bn128, PrimeOrderTestCurve, Secp256k1},
+        Scalar,
+    };
+
+    use super::*;
+
+    fn check_against_affine_addition<C: WeierstrassCurve + std::fmt::Debug>() {
+        let mut rng = rand::thread_rng();
+        let p = C::generator() * &Scalar::random(&mut rng);
+        let q = C::generator() * &Scalar::random(&mut rng);
+        let points = [
+            p.clone(),
+            -&p,
+            q,
+            C::generator(),
+            CurvePoint::point_at_infinity(),
+        ];
+
+        for p in &points {
+            for q in &points {
+                assert_eq!(p.add_complete(q), p + q);
+            }
+        }
+
+        // The result must not depend on the representation of the inputs.
+        let p_projective = ProjectivePoint::from(&p);
+        let p_doubled = p_projective.add(&p_projective);
+        assert_eq!(p_doubled.add(&p_projective).to_affine(), &(&p + &p) + &p);
+    }
+
+    /// Test the general formulas for `a != 0` on all points of a small curve.
+    #[test]
+    fn complete_addition_prime_order_test_curve() {
+        let points = (0..83)
+            .map(|scalar| PrimeOrderTestCurve::generator() * &Scalar::new(scalar))
+            .collect::<Vec<_>>();
+
+        for p in &points {
+            for q in &points {
+                assert_eq!(p.add_complete(q), p + q);
+            }
+        }
+    }
+
+    #[test]
+    fn conditional_swap() {
+        let p = Secp256k1::generator();
+        let q = -Secp256k1::generator();
+        let mut p_projective = ProjectivePoint::from(&p);
+        let mut q_projective = ProjectivePoint::from(&q);
+
+        p_projective.conditional_swap(&mut q_projective, false);
+        assert_eq!(p_projective.to_affine(), p);
+        assert_eq!(q_projective.to_affine(), q);
+
+        p_projective.conditional_swap(&mut q_projective, true);
+        assert_eq!(p_projective.to_affine(), q);
+        assert_eq!(q_projective.to_affine(), p);
+    }
+
+    #[test]
+    fn complete_addition_bn128() {
+        check_against_affine_addition::<Bn128>();
+    }
+
+    #[test]
+    fn complete_addition_secp256k1() {
+        check_against_affine_addition::<Secp256k1>();
+    }
+}
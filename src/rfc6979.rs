@@ -0,0 +1,215 @@
+use std::marker::PhantomData;
+
+use hmac::{
+    digest::{core_api::BlockSizeUser, Digest},
+    Mac, SimpleHmac,
+};
+use num::{bigint::Sign, BigInt};
+
+/// Deterministic generation of ECDSA nonces as specified in
+/// [RFC 6979](https://www.rfc-editor.org/rfc/rfc6979), Section 3.2.
+///
+/// The nonces are derived with an HMAC-DRBG over the hash function `D`, which is keyed on the
+/// secret key and the message hash. Signing the same message with the same key always yields
+/// the same nonce, while nonces for different messages are indistinguishable from random ones
+/// to anyone who does not know the secret key. This removes the dependency on a good source of
+/// randomness at signing time.
+///
+/// The generator yields an endless sequence of candidates in the range `[1, q)`. Usually the
+/// first candidate is used, the following ones are only needed if a candidate results in an
+/// invalid signature.
+pub struct NonceGenerator<D: Digest + BlockSizeUser> {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    order: BigInt,
+    /// Whether a candidate has been generated before, in which case the state must be updated
+    /// before generating the next one (step h.3 of the RFC).
+    needs_update: bool,
+    phantom: PhantomData<D>,
+}
+
+impl<D: Digest + BlockSizeUser> NonceGenerator<D> {
+    /// Creates the generator for the `secret` key and the hash `message_hash` of the message
+    /// to sign with a group of the given `order`.
+    ///
+    /// `message_hash` should have been computed with the same hash function `D`.
+    pub fn new(secret: &BigInt, message_hash: &[u8], order: &BigInt) -> Self {
+        let hash_len = <D as Digest>::output_size();
+        let mut generator = Self {
+            key: vec![0x00; hash_len],
+            value: vec![0x01; hash_len],
+            order: order.clone(),
+            needs_update: false,
+            phantom: PhantomData,
+        };
+
+        let secret = generator.int_to_octets(secret);
+        let message_hash = generator.bits_to_octets(message_hash);
+
+        for separator in [0x00, 0x01] {
+            generator.key =
+                generator.hmac(&[&generator.value, &[separator], &secret, &message_hash]);
+            generator.value = generator.hmac(&[&generator.value]);
+        }
+
+        generator
+    }
+
+    /// Computes `HMAC_K(data[0] || data[1] || ...)` with the current key `K`.
+    fn hmac(&self, data: &[&[u8]]) -> Vec<u8> {
+        // SAFETY: HMAC accepts keys of any length.
+        let mut mac = <SimpleHmac<D> as Mac>::new_from_slice(&self.key).unwrap();
+        for data in data {
+            mac.update(data);
+        }
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    /// Converts the integer to a big-endian byte string of the byte length of the order.
+    fn int_to_octets(&self, value: &BigInt) -> Vec<u8> {
+        let byte_len = self.order.bits().div_ceil(8) as usize;
+        let (_, bytes) = value.to_bytes_be();
+        let mut padded = vec![0; byte_len - bytes.len()];
+        padded.extend(bytes);
+        padded
+    }
+
+    /// Converts the hash to an integer, reduces it modulo the order and converts it back.
+    fn bits_to_octets(&self, bytes: &[u8]) -> Vec<u8> {
+        self.int_to_octets(&(bits_to_int(bytes, self.order.bits()) % &self.order))
+    }
+}
+
+impl<D: Digest + BlockSizeUser> Iterator for NonceGenerator<D> {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let order_bits = self.order.bits();
+
+        loop {
+            if self.needs_update {
+                self.key = self.hmac(&[&self.value, &[0x00]]);
+                self.value = self.hmac(&[&self.value]);
+            }
+            self.needs_update = true;
+
+            let mut bytes = Vec::new();
+            while (bytes.len() as u64 * 8) < order_bits {
+                self.value = self.hmac(&[&self.value]);
+                bytes.extend(&self.value);
+            }
+
+            let nonce = bits_to_int(&bytes, order_bits);
+            if nonce > BigInt::ZERO && nonce < self.order {
+                return Some(nonce);
+            }
+        }
+    }
+}
+
+/// Converts the big-endian `bytes` to an integer, keeping only the leftmost `bits` bits.
+pub(crate) fn bits_to_int(bytes: &[u8], bits: u64) -> BigInt {
+    let value = BigInt::from_bytes_be(Sign::Plus, bytes);
+    let byte_bits = 8 * bytes.len() as u64;
+
+    if byte_bits > bits {
+        value >> (byte_bits - bits)
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Sha224, Sha256, Sha384, Sha512};
+
+    use crate::test_utils::hex_int;
+
+    use super::*;
+
+    fn check_nonce<D: Digest + BlockSizeUser>(
+        secret: &BigInt,
+        order: &BigInt,
+        message: &str,
+        expected: &str,
+    ) {
+        let hash = D::digest(message.as_bytes());
+        let mut generator = NonceGenerator::<D>::new(secret, &hash, order);
+        assert_eq!(generator.next(), Some(hex_int(expected)), "{message}");
+    }
+
+    /// The nonces of the NIST P-256 test vectors from RFC 6979, Appendix A.2.5.
+    ///
+    /// The RFC has no test vectors for secp256k1 or bn128, but the nonces only depend on the
+    /// group order, the secret key and the message hash, not on the curve equation.
+    #[test]
+    fn rfc6979_nonces_p256() {
+        let order = hex_int("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+        let secret = hex_int("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+        check_nonce::<Sha224>(
+            &secret,
+            &order,
+            "sample",
+            "103F90EE9DC52E5E7FB5132B7033C63066D194321491862059967C715985D473",
+        );
+        check_nonce::<Sha256>(
+            &secret,
+            &order,
+            "sample",
+            "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+        );
+        check_nonce::<Sha384>(
+            &secret,
+            &order,
+            "sample",
+            "09F634B188CEFD98E7EC88B1AA9852D734D0BC272F7D2A47DECC6EBEB375AAD4",
+        );
+        check_nonce::<Sha512>(
+            &secret,
+            &order,
+            "sample",
+            "5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5",
+        );
+        check_nonce::<Sha224>(
+            &secret,
+            &order,
+            "test",
+            "669F4426F2688B8BE0DB3A6BD1989BDAEFFF84B649EEB84F3DD26080F667FAA7",
+        );
+        check_nonce::<Sha256>(
+            &secret,
+            &order,
+            "test",
+            "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0",
+        );
+        check_nonce::<Sha384>(
+            &secret,
+            &order,
+            "test",
+            "16AEFFA357260B04B1DD199693960740066C1A8F3E8EDD79070AA914D361B3B8",
+        );
+        check_nonce::<Sha512>(
+            &secret,
+            &order,
+            "test",
+            "6915D11632ACA3C40D5D51C08DAF9C555933819548784480E93499000D9F0B7F",
+        );
+    }
+
+    /// With a tiny order, most candidates are out of range and the generator has to retry.
+    #[test]
+    fn candidates_are_in_range() {
+        let order = BigInt::from(83);
+        let generator = NonceGenerator::<Sha256>::new(&BigInt::from(5), &[0xab; 32], &order);
+
+        let nonces = generator.take(20).collect::<Vec<_>>();
+        assert!(nonces
+            .iter()
+            .all(|nonce| *nonce > BigInt::ZERO && *nonce < order));
+
+        // The sequence is deterministic.
+        let generator = NonceGenerator::<Sha256>::new(&BigInt::from(5), &[0xab; 32], &order);
+        assert_eq!(generator.take(20).collect::<Vec<_>>(), nonces);
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use num::{bigint::Sign, traits::Euclid, BigInt};
+use rand::RngCore;
+
+use crate::{mod_mul_inverse, WeierstrassCurve};
+
+/// A scalar modulo the [`order`](WeierstrassCurve::order) of the curve `C`.
+///
+/// The value is always reduced, i.e. it lies in the range `[0, order)`.
+pub struct Scalar<C: WeierstrassCurve> {
+    value: BigInt,
+    phantom: PhantomData<C>,
+}
+
+impl<C: WeierstrassCurve> Debug for Scalar<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Scalar").field(&self.value).finish()
+    }
+}
+
+impl<C: WeierstrassCurve> Clone for Scalar<C> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: WeierstrassCurve> PartialEq for Scalar<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<C: WeierstrassCurve> Eq for Scalar<C> {}
+
+impl<C: WeierstrassCurve> Scalar<C> {
+    /// Creates a new scalar from `value`, reducing it modulo the group order.
+    pub fn new(value: impl Into<BigInt>) -> Self {
+        Self {
+            value: Euclid::rem_euclid(&value.into(), &C::order()),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the additive identity.
+    pub fn zero() -> Self {
+        Self::new(BigInt::ZERO)
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one() -> Self {
+        Self::new(1)
+    }
+
+    /// Samples a uniformly random, non-zero scalar from `rng`.
+    pub fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let order = C::order();
+        let mut bytes = vec![0; Self::byte_len()];
+
+        // Rejection sampling to avoid the bias that reducing a random value would introduce.
+        // The excess bits of the most significant byte are masked off so that at least half
+        // of all samples are accepted.
+        let excess_bits = bytes.len() * 8 - order.bits() as usize;
+        loop {
+            rng.fill_bytes(&mut bytes);
+            bytes[0] &= 0xff >> excess_bits;
+
+            let value = BigInt::from_bytes_be(Sign::Plus, &bytes);
+            if value != BigInt::ZERO && value < order {
+                return Self::new(value);
+            }
+        }
+    }
+
+    /// Creates a scalar from its big-endian byte representation.
+    ///
+    /// Returns `None` if the value is not smaller than the group order.
+    pub fn from_bytes_be(bytes: &[u8]) -> Option<Self> {
+        let value = BigInt::from_bytes_be(Sign::Plus, bytes);
+        if value >= C::order() {
+            return None;
+        }
+
+        Some(Self::new(value))
+    }
+
+    /// Creates a scalar from its big-endian byte representation, reducing it modulo the group order.
+    pub fn from_bytes_be_reduced(bytes: &[u8]) -> Self {
+        Self::new(BigInt::from_bytes_be(Sign::Plus, bytes))
+    }
+
+    /// Returns the big-endian byte representation of the scalar.
+    ///
+    /// The result is left-padded with zeros to the byte length of the group order.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let (_, bytes) = self.value.to_bytes_be();
+        let mut padded = vec![0; Self::byte_len() - bytes.len()];
+        padded.extend(bytes);
+        padded
+    }
+
+    /// Returns the number of bytes needed to represent any scalar.
+    pub fn byte_len() -> usize {
+        C::order().bits().div_ceil(8) as usize
+    }
+
+    /// Returns the reduced value of the scalar.
+    pub fn value(&self) -> &BigInt {
+        &self.value
+    }
+
+    /// Returns `true` if the scalar is zero.
+    pub fn is_zero(&self) -> bool {
+        self.value == BigInt::ZERO
+    }
+
+    /// Raises the scalar to the power of `exponent`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `exponent` is negative.
+    pub fn pow(&self, exponent: &BigInt) -> Self {
+        Self::new(self.value.modpow(exponent, &C::order()))
+    }
+
+    /// Returns the multiplicative inverse of the scalar or `None` if it does not exist.
+    ///
+    /// For prime order groups this is only the case for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let inverse = Self::new(mod_mul_inverse(self.value.clone(), C::order()));
+        (&inverse * self == Self::one()).then_some(inverse)
+    }
+}
+
+impl<C: WeierstrassCurve> Add<&Scalar<C>> for &Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn add(self, rhs: &Scalar<C>) -> Self::Output {
+        Scalar::new(&self.value + &rhs.value)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Add<&Scalar<C>> for Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn add(self, rhs: &Scalar<C>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Sub<&Scalar<C>> for &Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn sub(self, rhs: &Scalar<C>) -> Self::Output {
+        Scalar::new(&self.value - &rhs.value)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Sub<&Scalar<C>> for Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn sub(self, rhs: &Scalar<C>) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Mul<&Scalar<C>> for &Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn mul(self, rhs: &Scalar<C>) -> Self::Output {
+        Scalar::new(&self.value * &rhs.value)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Mul<&Scalar<C>> for Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn mul(self, rhs: &Scalar<C>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<C: WeierstrassCurve> Neg for &Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn neg(self) -> Self::Output {
+        Scalar::new(-&self.value)
+    }
+}
+
+// Additional implementation for convenience.
+impl<C: WeierstrassCurve> Neg for Scalar<C> {
+    type Output = Scalar<C>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        curves::{Bn128, Secp256k1, TestCurve},
+        CurvePoint,
+    };
+
+    use super::*;
+
+    #[test]
+    fn values_are_reduced() {
+        assert_eq!(Scalar::<TestCurve>::new(13).value(), &BigInt::from(1));
+        assert_eq!(Scalar::<TestCurve>::new(-1).value(), &BigInt::from(11));
+        assert_eq!(
+            Scalar::<TestCurve>::new(7) + &Scalar::new(5),
+            Scalar::zero()
+        );
+        assert_eq!(
+            Scalar::<TestCurve>::new(3) - &Scalar::new(5),
+            Scalar::new(10)
+        );
+        assert_eq!(
+            Scalar::<TestCurve>::new(3) * &Scalar::new(5),
+            Scalar::new(3)
+        );
+        assert_eq!(-Scalar::<TestCurve>::new(3), Scalar::new(9));
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(Scalar::<Secp256k1>::zero().inverse(), None);
+        // The test curve's order 12 is not prime, so only values coprime to 12 are invertible.
+        assert_eq!(Scalar::<TestCurve>::new(4).inverse(), None);
+        assert_eq!(Scalar::<TestCurve>::new(5).inverse(), Some(Scalar::new(5)));
+
+        let scalar = Scalar::<Secp256k1>::random(&mut rand::thread_rng());
+        assert_eq!(scalar.inverse().unwrap() * &scalar, Scalar::one());
+    }
+
+    #[test]
+    fn byte_conversion() {
+        let scalar = Scalar::<Secp256k1>::new(0x1234);
+        let bytes = scalar.to_bytes_be();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(&bytes[30..], &[0x12, 0x34]);
+        assert!(bytes[..30].iter().all(|byte| *byte == 0));
+        assert_eq!(Scalar::from_bytes_be(&bytes), Some(scalar));
+
+        let order_bytes = Secp256k1::order().to_bytes_be().1;
+        assert_eq!(Scalar::<Secp256k1>::from_bytes_be(&order_bytes), None);
+        assert_eq!(
+            Scalar::<Secp256k1>::from_bytes_be_reduced(&order_bytes),
+            Scalar::zero()
+        );
+
+        let scalar = Scalar::<Secp256k1>::random(&mut rand::thread_rng());
+        assert_eq!(Scalar::from_bytes_be(&scalar.to_bytes_be()), Some(scalar));
+    }
+
+    #[test]
+    fn random_scalars_are_in_range() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let scalar = Scalar::<TestCurve>::random(&mut rng);
+            assert!(!scalar.is_zero());
+            assert!(scalar.value() < &TestCurve::order());
+        }
+    }
+
+    /// Test that 9/7 * G + 5/7 * G = 2 * G using scalar field arithmetic.
+    #[test]
+    fn encoding_rational_numbers() {
+        let seven_inverse = Scalar::<Bn128>::new(7).inverse().unwrap();
+        let nine_over_seven = Scalar::new(9) * &seven_inverse;
+        let five_over_seven = Scalar::new(5) * &seven_inverse;
+
+        assert_eq!(
+            Bn128::generator() * &nine_over_seven + &(Bn128::generator() * &five_over_seven),
+            Bn128::generator() * &Scalar::new(2)
+        );
+    }
+
+    /// Test that multiplying with a negative integer is equivalent to multiplying with the
+    /// corresponding reduced scalar.
+    #[test]
+    fn negative_integers_are_reduced() {
+        let point = Secp256k1::generator() * &BigInt::from(-5);
+
+        assert_eq!(point, Secp256k1::generator() * &Scalar::new(-5));
+        assert_eq!(point, -(Secp256k1::generator() * &BigInt::from(5)));
+        assert_eq!(
+            CurvePoint::<TestCurve>::point_at_infinity() * &BigInt::from(-3),
+            CurvePoint::point_at_infinity()
+        );
+    }
+}
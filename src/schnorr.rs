@@ -0,0 +1,404 @@
+//! Schnorr signatures over [`Secp256k1`] as specified in
+//! [BIP-340](https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki),
+//! which are used by Bitcoin's Taproot.
+//!
+//! Public keys are x-only: only the x-coordinate of a public key is encoded and the
+//! y-coordinate is implicitly chosen to be even. Likewise, the nonce point `R` of a signature
+//! is encoded by its x-coordinate alone.
+
+use num::{bigint::Sign, BigInt};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::{curves::Secp256k1, CurvePoint, FieldElement, Scalar, WeierstrassCurve};
+
+/// Computes the tagged hash `SHA256(SHA256(tag) || SHA256(tag) || data[0] || data[1] || ...)`.
+///
+/// Prefixing the data with the hash of a tag makes hashes for different purposes independent
+/// of each other.
+pub fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for data in data {
+        hasher.update(data);
+    }
+    hasher.finalize().into()
+}
+
+/// An x-only public key, i.e. a point with an even y-coordinate that is encoded as its
+/// x-coordinate only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    point: CurvePoint<Secp256k1>,
+}
+
+impl PublicKey {
+    /// Decodes an x-only public key.
+    ///
+    /// Returns `None` if the value is not smaller than the field modulus or is not the
+    /// x-coordinate of a point on the curve.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        lift_x(bytes).map(|point| Self { point })
+    }
+
+    /// Returns the 32-byte encoding of the public key's x-coordinate.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        x_bytes(&self.point)
+    }
+
+    /// Returns the point with even y-coordinate that the public key represents.
+    pub fn point(&self) -> &CurvePoint<Secp256k1> {
+        &self.point
+    }
+}
+
+/// A secret key together with its x-only [`PublicKey`].
+///
+/// The secret key `d` is normalized such that `d * G` has an even y-coordinate, i.e. it is
+/// negated if the secret key it was created from corresponds to a point with odd y-coordinate.
+/// Both secret keys belong to the same x-only public key.
+#[derive(Debug, Clone)]
+pub struct Keypair {
+    secret: Scalar<Secp256k1>,
+    public: PublicKey,
+}
+
+impl Keypair {
+    /// Creates the key pair for the `secret` key or returns `None` if it is zero.
+    pub fn new(secret: Scalar<Secp256k1>) -> Option<Self> {
+        if secret.is_zero() {
+            return None;
+        }
+
+        let point = Secp256k1::generator().mul_ct(&secret);
+        let (point, secret) = if has_even_y(&point) {
+            (point, secret)
+        } else {
+            (-point, -secret)
+        };
+
+        Some(Self {
+            secret,
+            public: PublicKey { point },
+        })
+    }
+
+    /// Generates a key pair from a random secret key.
+    pub fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        // SAFETY: Random scalars are never zero.
+        Self::new(Scalar::random(rng)).unwrap()
+    }
+
+    /// Returns the normalized secret key.
+    pub fn secret(&self) -> &Scalar<Secp256k1> {
+        &self.secret
+    }
+
+    /// Returns the x-only public key.
+    pub fn public(&self) -> &PublicKey {
+        &self.public
+    }
+}
+
+/// A BIP-340 signature, consisting of the x-coordinate of the nonce point `R` and the
+/// scalar `s`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    r: FieldElement<Secp256k1>,
+    s: Scalar<Secp256k1>,
+}
+
+impl Signature {
+    /// Decodes a signature from its 64-byte encoding `x(R) || s`.
+    ///
+    /// Returns `None` if `x(R)` is not smaller than the field modulus or `s` is not smaller
+    /// than the group order. Whether `x(R)` belongs to a point on the curve is only checked
+    /// during verification.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Self> {
+        let r = BigInt::from_bytes_be(Sign::Plus, &bytes[..32]);
+        if r >= Secp256k1::field_modulus() {
+            return None;
+        }
+
+        Some(Self {
+            r: FieldElement::new(r),
+            s: Scalar::from_bytes_be(&bytes[32..])?,
+        })
+    }
+
+    /// Returns the 64-byte encoding `x(R) || s` of the signature.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.r.to_bytes_be());
+        bytes[32..].copy_from_slice(&self.s.to_bytes_be());
+        bytes
+    }
+}
+
+/// Signs `message` with the `keypair`.
+///
+/// The nonce is derived from the secret key, the public key, the message and the auxiliary
+/// randomness `aux_rand`. Fresh randomness is recommended to protect against side-channel
+/// attacks, but the signature remains secure even if `aux_rand` is fixed, e.g. all zeros.
+pub fn sign(keypair: &Keypair, message: &[u8], aux_rand: &[u8; 32]) -> Signature {
+    let public = keypair.public.to_bytes();
+
+    // Mask the secret key with the hashed auxiliary randomness.
+    let aux_hash = tagged_hash("BIP0340/aux", &[aux_rand]);
+    let mut masked_secret = keypair.secret.to_bytes_be();
+    for (byte, mask) in masked_secret.iter_mut().zip(aux_hash) {
+        *byte ^= mask;
+    }
+
+    let nonce_hash = tagged_hash("BIP0340/nonce", &[&masked_secret, &public, message]);
+    let nonce = Scalar::<Secp256k1>::from_bytes_be_reduced(&nonce_hash);
+    // The nonce is zero with negligible probability. BIP-340 aborts in that case.
+    assert!(!nonce.is_zero(), "nonce must not be zero");
+
+    let point = Secp256k1::generator().mul_ct(&nonce);
+    let (point, nonce) = if has_even_y(&point) {
+        (point, nonce)
+    } else {
+        (-point, -nonce)
+    };
+
+    let r = x_bytes(&point);
+    let challenge = challenge(&r, &public, message);
+    let s = nonce + &(challenge * &keypair.secret);
+
+    // SAFETY: The point is not the point at infinity since the nonce is non-zero.
+    let (r, _) = point.as_coordinates().unwrap();
+    Signature { r: r.clone(), s }
+}
+
+/// Verifies that `signature` is a valid signature of `message` for the `public` key.
+pub fn verify(public: &PublicKey, message: &[u8], signature: &Signature) -> bool {
+    let r = signature.r.to_bytes_be();
+    let challenge = challenge(&r, &public.to_bytes(), message);
+
+    // R = sG - eP
+    let point = CurvePoint::generator_mul(&signature.s) - &(&public.point * &challenge);
+
+    match point.as_coordinates() {
+        Some((x, _)) => has_even_y(&point) && *x == signature.r,
+        None => false,
+    }
+}
+
+/// Verifies all `(public key, message, signature)` triples at once.
+///
+/// Returns `true` only if all signatures are valid. This is faster than verifying the
+/// signatures one by one, since all signatures are combined into a single multi-scalar
+/// multiplication. To prevent invalid signatures from cancelling each other out, the
+/// signatures are weighted with random factors drawn from `rng`.
+pub fn verify_batch<R: RngCore + ?Sized>(
+    items: &[(&PublicKey, &[u8], &Signature)],
+    rng: &mut R,
+) -> bool {
+    // Check that sum(a_i * s_i) * G - sum(a_i * R_i) - sum(a_i * e_i * P_i) is the point at
+    // infinity for random a_i, where a_1 = 1.
+    let mut terms = Vec::with_capacity(2 * items.len() + 1);
+    let mut s_sum = Scalar::zero();
+
+    for (i, (public, message, signature)) in items.iter().enumerate() {
+        let r = signature.r.to_bytes_be();
+        // SAFETY: The encoding of a field element has exactly 32 bytes.
+        let Some(point_r) = lift_x(&r.clone().try_into().unwrap()) else {
+            return false;
+        };
+        let challenge = challenge(&r, &public.to_bytes(), message);
+
+        let factor = if i == 0 {
+            Scalar::one()
+        } else {
+            Scalar::random(rng)
+        };
+
+        s_sum = s_sum + &(&factor * &signature.s);
+        terms.push((-&(&factor * &challenge), public.point.clone()));
+        terms.push((-factor, point_r));
+    }
+    terms.push((s_sum, Secp256k1::generator()));
+
+    CurvePoint::multi_scalar_mul(&terms) == CurvePoint::point_at_infinity()
+}
+
+/// Computes the challenge `e = hash_challenge(x(R) || x(P) || m) mod n`.
+fn challenge(r: &[u8], public: &[u8], message: &[u8]) -> Scalar<Secp256k1> {
+    Scalar::from_bytes_be_reduced(&tagged_hash("BIP0340/challenge", &[r, public, message]))
+}
+
+/// Returns the point with the given x-coordinate and an even y-coordinate, if it exists.
+fn lift_x(x: &[u8; 32]) -> Option<CurvePoint<Secp256k1>> {
+    CurvePoint::from_sec1(&[&[0x02], &x[..]].concat()).ok()
+}
+
+/// Returns `true` if the point is not the point at infinity and has an even y-coordinate.
+fn has_even_y(point: &CurvePoint<Secp256k1>) -> bool {
+    point
+        .as_coordinates()
+        .is_some_and(|(_, y)| !y.value().bit(0))
+}
+
+/// Returns the 32-byte encoding of the point's x-coordinate.
+fn x_bytes(point: &CurvePoint<Secp256k1>) -> [u8; 32] {
+    // SAFETY: The points passed to this function are never the point at infinity
+    // and the encoding of a field element has exactly 32 bytes.
+    let (x, _) = point.as_coordinates().unwrap();
+    x.to_bytes_be().try_into().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::hex;
+
+    use super::*;
+
+    struct TestVector {
+        index: usize,
+        secret_key: Option<[u8; 32]>,
+        public_key: [u8; 32],
+        aux_rand: Option<[u8; 32]>,
+        message: Vec<u8>,
+        signature: [u8; 64],
+        is_valid: bool,
+        comment: String,
+    }
+
+    /// Parses the official BIP-340 test vectors.
+    fn test_vectors() -> Vec<TestVector> {
+        include_str!("../tests/fixtures/bip340-test-vectors.csv")
+            .lines()
+            .skip(1)
+            .map(|line| {
+                let columns = line.splitn(8, ',').collect::<Vec<_>>();
+                let optional =
+                    |value: &str| (!value.is_empty()).then(|| hex(value).try_into().unwrap());
+
+                TestVector {
+                    index: columns[0].parse().unwrap(),
+                    secret_key: optional(columns[1]),
+                    public_key: hex(columns[2]).try_into().unwrap(),
+                    aux_rand: optional(columns[3]),
+                    message: hex(columns[4]),
+                    signature: hex(columns[5]).try_into().unwrap(),
+                    is_valid: columns[6] == "TRUE",
+                    comment: columns[7].to_owned(),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tagged_hash_of_empty_input() {
+        // SHA256(SHA256("") || SHA256("")).
+        let empty_hash = Sha256::digest([]);
+        let expected: [u8; 32] = Sha256::digest([empty_hash, empty_hash].concat()).into();
+        assert_eq!(tagged_hash("", &[]), expected);
+    }
+
+    #[test]
+    fn bip340_test_vectors() {
+        for vector in test_vectors() {
+            let context = format!("vector {}: {}", vector.index, vector.comment);
+
+            if let (Some(secret_key), Some(aux_rand)) = (vector.secret_key, vector.aux_rand) {
+                let keypair = Keypair::new(Scalar::from_bytes_be(&secret_key).unwrap()).unwrap();
+                assert_eq!(keypair.public().to_bytes(), vector.public_key, "{context}");
+
+                let signature = sign(&keypair, &vector.message, &aux_rand);
+                assert_eq!(signature.to_bytes(), vector.signature, "{context}");
+            }
+
+            let is_valid = match (
+                PublicKey::from_bytes(&vector.public_key),
+                Signature::from_bytes(&vector.signature),
+            ) {
+                (Some(public), Some(signature)) => {
+                    verify(&public, &vector.message, &signature)
+                        && verify_batch(
+                            &[(&public, &vector.message, &signature)],
+                            &mut rand::thread_rng(),
+                        )
+                }
+                _ => false,
+            };
+            assert_eq!(is_valid, vector.is_valid, "{context}");
+        }
+    }
+
+    #[test]
+    fn keys_are_normalized_to_even_y() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let secret = Scalar::<Secp256k1>::random(&mut rng);
+            let keypair = Keypair::new(secret.clone()).unwrap();
+            let negated_keypair = Keypair::new(-&secret).unwrap();
+
+            // Both secret keys belong to the same x-only public key.
+            assert_eq!(keypair.public(), negated_keypair.public());
+            assert_eq!(keypair.secret(), negated_keypair.secret());
+            assert!(has_even_y(keypair.public().point()));
+            assert_eq!(
+                CurvePoint::generator_mul(keypair.secret()),
+                *keypair.public().point()
+            );
+        }
+
+        assert!(Keypair::new(Scalar::zero()).is_none());
+    }
+
+    #[test]
+    fn sign_and_verify_messages_of_any_length() {
+        let mut rng = rand::thread_rng();
+        let keypair = Keypair::random(&mut rng);
+
+        for message in [&b""[..], &[0x11], &[0x42; 17], &[0x99; 100]] {
+            let signature = sign(&keypair, message, &rand::random());
+            assert!(verify(keypair.public(), message, &signature));
+
+            let decoded = Signature::from_bytes(&signature.to_bytes()).unwrap();
+            assert!(verify(keypair.public(), message, &decoded));
+            assert!(!verify(keypair.public(), b"other message", &signature));
+        }
+    }
+
+    #[test]
+    fn batch_verification() {
+        let mut rng = rand::thread_rng();
+        let keypairs = (0..8)
+            .map(|_| Keypair::random(&mut rng))
+            .collect::<Vec<_>>();
+        let messages = (0..8u8).map(|i| vec![i; i as usize]).collect::<Vec<_>>();
+        let signatures = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(keypair, message)| sign(keypair, message, &rand::random()))
+            .collect::<Vec<_>>();
+
+        let items = keypairs
+            .iter()
+            .zip(&messages)
+            .zip(&signatures)
+            .map(|((keypair, message), signature)| {
+                (keypair.public(), message.as_slice(), signature)
+            })
+            .collect::<Vec<_>>();
+        assert!(verify_batch(&items, &mut rng));
+        assert!(verify_batch(&[], &mut rng));
+
+        // A single invalid signature invalidates the batch.
+        let mut invalid_items = items.clone();
+        invalid_items[3].1 = b"other message";
+        assert!(!verify_batch(&invalid_items, &mut rng));
+
+        // Signatures of the wrong keys, where the first item is unweighted.
+        let mut invalid_items = items.clone();
+        invalid_items[0].2 = &signatures[1];
+        invalid_items[1].2 = &signatures[0];
+        assert!(!verify_batch(&invalid_items, &mut rng));
+    }
+}
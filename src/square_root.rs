@@ -0,0 +1,219 @@
+use num::{traits::Euclid, BigInt, Integer};
+
+/// Computes a square root `r` of `a mod p` for an odd prime `p`, such that `r * r mod p = a mod p`,
+/// or returns `None` if `a` is not a quadratic residue mod `p`.
+///
+/// The returned root is in the range `[0, p)`, the other root is `p - r`. Uses the `p = 3 mod 4`
+/// shortcut if applicable, which covers the fields of secp256k1 and bn128,
+/// and the Tonelli-Shanks algorithm otherwise.
+///
+/// ## Example
+///
+/// For example, the square roots of `5 mod 11` are `4` and `7` because `4 * 4 mod 11 = 5`
+/// and `7 * 7 mod 11 = 5`, but `2 mod 11` has no square root.
+///
+/// ```
+/// # use num::BigInt;
+/// # use ecc::mod_sqrt;
+/// let root = mod_sqrt(5.into(), 11.into()).unwrap();
+/// assert!(root == BigInt::from(4) || root == BigInt::from(7));
+/// assert_eq!(mod_sqrt(2.into(), 11.into()), None);
+/// ```
+pub fn mod_sqrt(a: BigInt, p: BigInt) -> Option<BigInt> {
+    let a = Euclid::rem_euclid(&a, &p);
+    if a == BigInt::ZERO {
+        return Some(a);
+    }
+
+    if legendre_symbol(a.clone(), p.clone()) != 1 {
+        return None;
+    }
+
+    let one = BigInt::from(1);
+    if Euclid::rem_euclid(&p, &BigInt::from(4)) == BigInt::from(3) {
+        return Some(a.modpow(&((&p + &one) >> 2), &p));
+    }
+
+    // Write p - 1 = q * 2^s with q odd.
+    let p_minus_one = &p - &one;
+    let s = p_minus_one.trailing_zeros().unwrap();
+    let q = &p_minus_one >> s;
+
+    // Find a quadratic non-residue z.
+    let mut z = BigInt::from(2);
+    while legendre_symbol(z.clone(), p.clone()) != -1 {
+        z += 1;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, &p);
+    let mut t = a.modpow(&q, &p);
+    let mut r = a.modpow(&((&q + &one) >> 1), &p);
+
+    while t != one {
+        // Find the least i such that t^(2^i) = 1.
+        let mut i = 0;
+        let mut t_squared = t.clone();
+        while t_squared != one {
+            t_squared = &t_squared * &t_squared % &p;
+            i += 1;
+        }
+
+        let b = c.modpow(&(BigInt::from(1) << (m - i - 1)), &p);
+        m = i;
+        c = &b * &b % &p;
+        t = t * &c % &p;
+        r = r * &b % &p;
+    }
+
+    Some(r)
+}
+
+/// Computes the Legendre symbol `(a / p)` for an odd prime `p`.
+///
+/// The result is `1` if `a` is a non-zero quadratic residue mod `p`, `-1` if it is a
+/// non-residue and `0` if `a` is divisible by `p`.
+///
+/// ## Panics
+///
+/// Panics if `p` is not positive and odd. Primality is not checked.
+pub fn legendre_symbol(a: BigInt, p: BigInt) -> i8 {
+    // For prime p, the Jacobi symbol coincides with the Legendre symbol.
+    jacobi_symbol(a, p)
+}
+
+/// Computes the Jacobi symbol `(a / n)` for a positive odd `n`,
+/// which generalizes the Legendre symbol to composite `n`.
+///
+/// Note that for composite `n`, a result of `1` does not imply that `a` is a quadratic residue.
+/// A result of `-1` always implies that it is not.
+///
+/// ## Panics
+///
+/// Panics if `n` is not positive and odd.
+pub fn jacobi_symbol(a: BigInt, n: BigInt) -> i8 {
+    assert!(n > BigInt::ZERO && n.is_odd(), "n must be positive and odd");
+
+    let mut a = Euclid::rem_euclid(&a, &n);
+    let mut n = n;
+    let mut result = 1;
+
+    while a != BigInt::ZERO {
+        // Pull out factors of two using (2 / n) = -1 iff n = 3 or 5 mod 8.
+        let twos = a.trailing_zeros().unwrap();
+        a >>= twos;
+        let n_mod_8 = Euclid::rem_euclid(&n, &BigInt::from(8));
+        if twos % 2 == 1 && (n_mod_8 == BigInt::from(3) || n_mod_8 == BigInt::from(5)) {
+            result = -result;
+        }
+
+        // Quadratic reciprocity: (a / n) = -(n / a) iff both are 3 mod 4.
+        std::mem::swap(&mut a, &mut n);
+        let three = BigInt::from(3);
+        if Euclid::rem_euclid(&a, &BigInt::from(4)) == three
+            && Euclid::rem_euclid(&n, &BigInt::from(4)) == three
+        {
+            result = -result;
+        }
+        a = Euclid::rem_euclid(&a, &n);
+    }
+
+    if n == BigInt::from(1) {
+        result
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::Sign;
+
+    use super::*;
+
+    const SMALL_PRIMES: [u32; 10] = [3, 5, 7, 11, 13, 17, 41, 97, 113, 257];
+
+    #[test]
+    fn legendre_symbol_matches_euler_criterion() {
+        for p in SMALL_PRIMES {
+            for a in 0..p {
+                let euler = BigInt::from(a).modpow(&BigInt::from((p - 1) / 2), &BigInt::from(p));
+                let expected = match euler {
+                    euler if euler == BigInt::ZERO => 0,
+                    euler if euler == BigInt::from(1) => 1,
+                    _ => -1,
+                };
+
+                assert_eq!(legendre_symbol(a.into(), p.into()), expected, "({a} / {p})");
+            }
+        }
+    }
+
+    #[test]
+    fn jacobi_symbol_is_multiplicative_in_n() {
+        for (n1, n2) in [(3, 5), (7, 11), (13, 17), (3, 3), (5, 97)] {
+            for a in -20..100 {
+                assert_eq!(
+                    jacobi_symbol(a.into(), (n1 * n2).into()),
+                    jacobi_symbol(a.into(), n1.into()) * jacobi_symbol(a.into(), n2.into()),
+                    "({a} / {n1} * {n2})"
+                );
+            }
+        }
+
+        assert_eq!(jacobi_symbol(5.into(), 1.into()), 1);
+        assert_eq!(jacobi_symbol(0.into(), 1.into()), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "n must be positive and odd")]
+    fn jacobi_symbol_rejects_even_n() {
+        jacobi_symbol(3.into(), 10.into());
+    }
+
+    #[test]
+    fn square_roots_small_primes() {
+        for p in SMALL_PRIMES {
+            let residues = (0..p).map(|x| x * x % p).collect::<Vec<_>>();
+
+            for a in 0..p {
+                match mod_sqrt(a.into(), p.into()) {
+                    Some(root) => {
+                        assert!(root >= BigInt::ZERO && root < BigInt::from(p));
+                        assert_eq!(&root * &root % p, BigInt::from(a));
+                    }
+                    None => assert!(!residues.contains(&a), "{a} has a root mod {p}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn square_roots_large_primes() {
+        let primes = [
+            // secp256k1, p = 3 mod 4.
+            BigInt::parse_bytes(
+                b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+                16,
+            )
+            .unwrap(),
+            // P-224, p = 1 mod 2^96, the worst case for Tonelli-Shanks.
+            (BigInt::from(1) << 224) - (BigInt::from(1) << 96) + 1,
+        ];
+
+        for p in primes {
+            for _ in 0..10 {
+                let x = BigInt::from_bytes_be(Sign::Plus, &rand::random::<[u8; 32]>()) % &p;
+                let square = &x * &x % &p;
+
+                let root = mod_sqrt(square, p.clone()).unwrap();
+                assert!(root == x || root == &p - &x);
+            }
+
+            // -1 is a non-residue for p = 3 mod 4, but a residue for p = 1 mod 4.
+            let minus_one = &p - 1;
+            let is_residue = Euclid::rem_euclid(&p, &BigInt::from(4)) == BigInt::from(1);
+            assert_eq!(mod_sqrt(minus_one, p.clone()).is_some(), is_residue);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Helpers shared by the tests of several modules.
+
+use num::BigInt;
+
+/// Decodes a hexadecimal string with an even number of digits into bytes.
+pub(crate) fn hex(value: &str) -> Vec<u8> {
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Parses a hexadecimal string into a non-negative integer.
+pub(crate) fn hex_int(value: &str) -> BigInt {
+    BigInt::parse_bytes(value.as_bytes(), 16).unwrap()
+}
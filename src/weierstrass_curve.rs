@@ -1,16 +1,39 @@
-use crate::CurvePoint;
+use crate::{CurvePoint, Endomorphism, GeneratorTable, MontgomeryParameters};
 use num::BigInt;
 
 /// Parameter definitions for Weierstrass elliptic curves.
-pub trait WeierstrassCurve {
+pub trait WeierstrassCurve: 'static {
     /// Returns the generator point of the curve.
     fn generator() -> CurvePoint<Self>
+    where
+        Self: Sized;
+    /// Returns the precomputed table of generator multiples used by [`CurvePoint::generator_mul`].
+    fn generator_table() -> &'static GeneratorTable<Self>
     where
         Self: Sized;
     /// Returns the parameter `a` of the curve.
     fn a() -> BigInt;
+    /// Returns the parameter `b` of the curve.
+    fn b() -> BigInt;
     /// Returns the field modulus of the curve.
     fn field_modulus() -> BigInt;
+    /// Returns the precomputed [`MontgomeryParameters`] of the [`field_modulus`](WeierstrassCurve::field_modulus),
+    /// which [`FieldElement`](crate::FieldElement) uses for its arithmetic.
+    fn montgomery_parameters() -> &'static MontgomeryParameters;
+    /// Returns the order `n` of the generator, i.e. the smallest `n` such that `n * G` is the point at infinity.
+    fn order() -> BigInt;
+    /// Returns the cofactor `h` of the curve, i.e. the number of points on the curve divided by the [`order`](WeierstrassCurve::order).
+    fn cofactor() -> BigInt;
+    /// Returns the curve's efficient [`Endomorphism`], if it has one.
+    ///
+    /// If this returns `Some`, multiplying a [`CurvePoint`] with a [`Scalar`](crate::Scalar)
+    /// uses the GLV method, which roughly halves the number of doublings.
+    fn endomorphism() -> Option<&'static Endomorphism<Self>>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -19,54 +42,30 @@ mod tests {
 
     use k256::elliptic_curve::{self, sec1::ToEncodedPoint};
     use num::{bigint::Sign, traits::Euclid};
-    use once_cell::sync::Lazy;
 
     use crate::{
-        curves::{Bn128, Secp256k1},
-        mod_mul_inverse, Point,
+        curves::{Bls12_381, Bn128, PrimeOrderTestCurve, Secp256k1, TestCurve, P256, P384, P521},
+        mod_mul_inverse, Point, PointError, Scalar,
     };
 
     use super::*;
 
-    static GENERATOR: Lazy<CurvePoint<TestCurve>> =
-        Lazy::new(|| CurvePoint::new(BigInt::from(4), BigInt::from(10)));
-    static FIELD_MODULUS: Lazy<BigInt> = Lazy::new(|| BigInt::from(11));
-    /// A test curve for initial testing with a small modulus.
-    #[derive(Debug, PartialEq, Eq)]
-    struct TestCurve;
-    impl WeierstrassCurve for TestCurve {
-        fn generator() -> CurvePoint<Self>
-        where
-            Self: Sized,
-        {
-            GENERATOR.clone()
-        }
-
-        fn a() -> BigInt {
-            BigInt::ZERO
-        }
-
-        fn field_modulus() -> BigInt {
-            FIELD_MODULUS.clone()
-        }
-    }
-
     #[test]
     fn can_generate_all_test_curve_points() {
         let expected_points = [
-            CurvePoint::new(4, 10),
-            CurvePoint::new(7, 7),
-            CurvePoint::new(1, 9),
-            CurvePoint::new(0, 6),
-            CurvePoint::new(8, 8),
-            CurvePoint::new(2, 0),
-            CurvePoint::new(8, 3),
-            CurvePoint::new(0, 5),
-            CurvePoint::new(1, 2),
-            CurvePoint::new(7, 4),
-            CurvePoint::new(4, 1),
+            CurvePoint::new_unchecked(4, 10),
+            CurvePoint::new_unchecked(7, 7),
+            CurvePoint::new_unchecked(1, 9),
+            CurvePoint::new_unchecked(0, 6),
+            CurvePoint::new_unchecked(8, 8),
+            CurvePoint::new_unchecked(2, 0),
+            CurvePoint::new_unchecked(8, 3),
+            CurvePoint::new_unchecked(0, 5),
+            CurvePoint::new_unchecked(1, 2),
+            CurvePoint::new_unchecked(7, 4),
+            CurvePoint::new_unchecked(4, 1),
             CurvePoint::point_at_infinity(),
-            CurvePoint::new(4, 10),
+            CurvePoint::new_unchecked(4, 10),
         ]
         .to_vec();
 
@@ -83,12 +82,68 @@ mod tests {
         assert_eq!(expected_points, computed_points);
     }
 
+    /// Test that the generator satisfies the curve equation and that multiplying it by the order
+    /// yields the point at infinity.
+    #[test]
+    fn curve_parameters_are_consistent() {
+        fn check<C: WeierstrassCurve + std::fmt::Debug + PartialEq>() {
+            assert!(C::generator().is_on_curve());
+
+            assert_eq!(
+                C::generator() * &C::order(),
+                CurvePoint::point_at_infinity()
+            );
+        }
+
+        check::<TestCurve>();
+        check::<PrimeOrderTestCurve>();
+        check::<Bn128>();
+        check::<Secp256k1>();
+        check::<Bls12_381>();
+        check::<P256>();
+        check::<P384>();
+        check::<P521>();
+    }
+
+    #[test]
+    fn try_new_validates_points() {
+        assert_eq!(
+            CurvePoint::<TestCurve>::try_new(7, 7),
+            Ok(CurvePoint::new_unchecked(7, 7))
+        );
+        assert_eq!(
+            CurvePoint::<TestCurve>::try_new(7, 8),
+            Err(PointError::NotOnCurve)
+        );
+        assert_eq!(
+            CurvePoint::<TestCurve>::try_new(7 + 11, 7),
+            Err(PointError::CoordinateOutOfRange)
+        );
+        assert_eq!(
+            CurvePoint::<TestCurve>::try_new(7, -4),
+            Err(PointError::CoordinateOutOfRange)
+        );
+
+        let (x, y) = Secp256k1::generator()
+            .as_coordinates()
+            .map(|(x, y)| (x.value(), y.value()))
+            .unwrap();
+        assert_eq!(
+            CurvePoint::<Secp256k1>::try_new(x.clone(), y.clone()),
+            Ok(Secp256k1::generator())
+        );
+        assert_eq!(
+            CurvePoint::<Secp256k1>::try_new(x, y + 1),
+            Err(PointError::NotOnCurve)
+        );
+    }
+
     #[test]
     fn scalar_point_multiplication() {
         // Equal to 2^8 + 2^4 + 2^2 + 2^0 to test doubling implementation.
         let scalar = 256 + 16 + 4 + 1;
 
-        let multiplication = TestCurve::generator() * &scalar.into();
+        let multiplication = TestCurve::generator() * &BigInt::from(scalar);
 
         let mut addition_result = CurvePoint::point_at_infinity();
         for _ in 0..scalar {
@@ -102,7 +157,7 @@ mod tests {
     fn scalar_point_multiplication_bn128() {
         // Expected result computed with py_ecc.
         let scalar = 300_000_000;
-        let expected_result = CurvePoint::new(
+        let expected_result = CurvePoint::new_unchecked(
             BigInt::parse_bytes(
                 b"12600240597266143967986535800884193324885833839429757878922176041119260815197",
                 10,
@@ -115,14 +170,14 @@ mod tests {
             .unwrap(),
         );
 
-        let actual_result = Bn128::generator() * &scalar.into();
+        let actual_result = Bn128::generator() * &BigInt::from(scalar);
 
         assert_eq!(expected_result, actual_result);
     }
 
     #[test]
     fn negate_points() {
-        let random_point = Bn128::generator() * &5000.into();
+        let random_point = Bn128::generator() * &BigInt::from(5000);
         let negated = random_point.negate();
 
         assert_eq!(random_point + &negated, Point::PointAtInfinity.into());
@@ -137,13 +192,13 @@ mod tests {
     #[test]
     fn multiplication_is_associative() {
         let left_first = Add::add(
-            Bn128::generator() * &5.into() + &(Bn128::generator() * &15.into()),
-            &(Bn128::generator() * &7.into()),
+            Bn128::generator() * &BigInt::from(5) + &(Bn128::generator() * &BigInt::from(15)),
+            &(Bn128::generator() * &BigInt::from(7)),
         );
 
         let right_first = Add::add(
-            Bn128::generator() * &5.into(),
-            &(Bn128::generator() * &15.into() + &(Bn128::generator() * &7.into())),
+            Bn128::generator() * &BigInt::from(5),
+            &(Bn128::generator() * &BigInt::from(15) + &(Bn128::generator() * &BigInt::from(7))),
         );
 
         assert_eq!(left_first, right_first);
@@ -153,11 +208,7 @@ mod tests {
     /// This works because G * x = G * (x + curve_order), which implies (x + y) * G mod curve_order = x * G + y * G.
     #[test]
     fn encoding_rational_numbers() {
-        let curve_order_bn128 = BigInt::parse_bytes(
-            b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
-            10,
-        )
-        .unwrap();
+        let curve_order_bn128 = Bn128::order();
 
         // Encodes the rational number 9 * 1/7.
         let nine_over_seven =
@@ -168,7 +219,7 @@ mod tests {
         let one_half = BigInt::from(5) * mod_mul_inverse(7.into(), curve_order_bn128.clone());
         let five_over_seven = Euclid::rem_euclid(&one_half, &curve_order_bn128);
 
-        let whole_multiplication = Bn128::generator() * &2.into();
+        let whole_multiplication = Bn128::generator() * &BigInt::from(2);
         let rational_multiplication =
             Bn128::generator() * &nine_over_seven + &(Bn128::generator() * &five_over_seven);
 
@@ -194,8 +245,50 @@ mod tests {
         let public_key_x = BigInt::from_bytes_be(Sign::Plus, encoded_point.x().unwrap());
         let public_key_y = BigInt::from_bytes_be(Sign::Plus, encoded_point.y().unwrap());
 
-        assert_eq!(x, &public_key_x);
-        assert_eq!(y, &public_key_y);
+        assert_eq!(x.value(), public_key_x);
+        assert_eq!(y.value(), public_key_y);
+        assert_eq!(curve_pk.to_sec1(false), encoded_point.as_bytes());
+        assert_eq!(
+            curve_pk.to_sec1(true),
+            public_key.to_encoded_point(true).as_bytes()
+        );
+    }
+
+    /// Test that the public key computation of the RustCrypto NIST curve crates and this library
+    /// are equivalent, which exercises point doubling with `a != 0`.
+    #[test]
+    fn nist_public_key_calculation() {
+        fn check<C: WeierstrassCurve>(public_key: impl Fn(&[u8]) -> Vec<u8>) {
+            let secret = Scalar::<C>::random(&mut rand::thread_rng());
+            let curve_pk = C::generator() * &secret;
+
+            assert_eq!(curve_pk.to_sec1(false), public_key(&secret.to_bytes_be()));
+        }
+
+        check::<P256>(|secret| {
+            let secret_key = p256::SecretKey::from_slice(secret).unwrap();
+            secret_key
+                .public_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec()
+        });
+        check::<P384>(|secret| {
+            let secret_key = p384::SecretKey::from_slice(secret).unwrap();
+            secret_key
+                .public_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec()
+        });
+        check::<P521>(|secret| {
+            let secret_key = p521::SecretKey::from_slice(secret).unwrap();
+            secret_key
+                .public_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec()
+        });
     }
 
     /// Test that ECDH run by k256 and this libary are equivalent.
@@ -239,8 +332,7 @@ mod tests {
             .as_coordinates()
             .unwrap()
             .0
-            .to_bytes_be()
-            .1;
+            .to_bytes_be();
 
         assert_eq!(shared_secret_x, curve_shared_secret_x);
     }
@@ -0,0 +1,77 @@
+use num::{traits::Euclid, BigInt, Signed};
+
+/// Computes the width-`window` non-adjacent form (wNAF) of the non-negative `scalar`.
+///
+/// The returned digits are ordered from least to most significant, such that
+/// `scalar = sum(digits[i] * 2^i)`. Every non-zero digit is odd and its absolute value is smaller
+/// than `2^(window - 1)`, and any `window` consecutive digits contain at most one non-zero digit.
+///
+/// ## Panics
+///
+/// Panics if `scalar` is negative or `window` is not in the range `[2, 16]`.
+pub(crate) fn wnaf(scalar: &BigInt, window: usize) -> Vec<i32> {
+    assert!(!scalar.is_negative(), "scalar must be non-negative");
+    assert!(
+        (2..=16).contains(&window),
+        "window must be in the range [2, 16]"
+    );
+
+    let modulus = BigInt::from(1) << window;
+    let half_modulus = 1i32 << (window - 1);
+
+    let mut digits = Vec::with_capacity(scalar.bits() as usize + 1);
+    let mut scalar = scalar.clone();
+
+    while scalar.is_positive() {
+        let digit = if scalar.bit(0) {
+            // SAFETY: The remainder is smaller than 2^16 and hence fits into an i32.
+            let mut digit: i32 = Euclid::rem_euclid(&scalar, &modulus).try_into().unwrap();
+            if digit >= half_modulus {
+                digit -= 1 << window;
+            }
+            scalar -= digit;
+            digit
+        } else {
+            0
+        };
+
+        digits.push(digit);
+        scalar >>= 1;
+    }
+
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::Sign;
+
+    use super::*;
+
+    #[test]
+    fn wnaf_recoding() {
+        for window in 2..=8 {
+            for _ in 0..20 {
+                let scalar = BigInt::from_bytes_be(Sign::Plus, &rand::random::<[u8; 32]>());
+                let digits = wnaf(&scalar, window);
+
+                let recomposed = digits
+                    .iter()
+                    .rev()
+                    .fold(BigInt::ZERO, |acc, digit| acc * 2 + digit);
+                assert_eq!(recomposed, scalar);
+
+                for (i, digit) in digits.iter().enumerate() {
+                    if *digit != 0 {
+                        assert_eq!(digit.abs() % 2, 1);
+                        assert!(digit.abs() < 1 << (window - 1));
+                        assert!(digits[i + 1..].iter().take(window - 1).all(|d| *d == 0));
+                    }
+                }
+            }
+        }
+
+        assert!(wnaf(&BigInt::ZERO, 4).is_empty());
+        assert_eq!(wnaf(&BigInt::from(7), 2), vec![-1, 0, 0, 1]);
+    }
+}